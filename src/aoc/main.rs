@@ -0,0 +1,521 @@
+use std::{
+    env, fs,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use advent_of_code::{
+    bench::{change, Baseline, Benchmark, PHASES},
+    generate::{generate_to, scaled_size},
+    input::{day_path, inputs_directory, InputSource},
+    ledger::{hash_input, ledger_path, Ledger, Verdict},
+    output::{json_array, json_error, json_record, json_records, Format},
+    runner::{days, get_day, Answer, Parts},
+};
+use anyhow::{anyhow, bail, Context};
+
+const USAGE: &str = "Usage: aoc <day> [1|2|both] [input|-] [options]
+       aoc all [options]
+
+Options:
+    --inputs <directory>    Directory containing day_N/input.txt
+    --format <text|json>    Print answers as text or as JSON records
+    --ledger <path>         Ledger of known-correct answers
+    --record                Record this run's answers in the ledger
+    --bench                 Time parsing and each part instead of checking answers
+    --runs <n>              Number of benchmark runs, defaults to 10
+    --baseline <path>       Compare benchmark medians against a saved baseline
+    --save-baseline <path>  Save benchmark medians as a new baseline
+    --tolerance <percent>   Allowed slowdown against the baseline, defaults to 10
+    --generate              Print a random input instead, or write every day's to --output
+    --size <n>              Size of the generated input, in elves, rounds, lines and so on
+    --scale <n>             Generate inputs n times the size of a normal input
+    --seed <n>              Seed for the generated input, defaults to 0
+    --output <directory>    Directory to write day_N/input.txt to when generating all days";
+
+enum Target {
+    Day(u8),
+    All,
+}
+
+struct Options {
+    target: Target,
+    parts: Parts,
+    input: Option<InputSource>,
+    inputs: PathBuf,
+    ledger: PathBuf,
+    record: bool,
+    format: Format,
+    bench: bool,
+    runs: usize,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    tolerance: f64,
+    generate: bool,
+    size: Option<usize>,
+    scale: usize,
+    seed: u64,
+    output: Option<PathBuf>,
+}
+
+impl Options {
+    fn source(&self, day: u8) -> InputSource {
+        match (&self.target, &self.input) {
+            (Target::Day(target), Some(source)) if *target == day => source.clone(),
+            _ => InputSource::Directory(self.inputs.clone()),
+        }
+    }
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> anyhow::Result<String> {
+    args.next()
+        .ok_or_else(|| anyhow!("{} expects a value\n{}", flag, USAGE))
+}
+
+fn parse_args(args: Vec<String>) -> anyhow::Result<Options> {
+    let mut positional = vec![];
+    let mut inputs = None;
+    let mut ledger = None;
+    let mut record = false;
+    let mut format = Format::Text;
+    let mut bench = false;
+    let mut runs = 10;
+    let mut baseline = None;
+    let mut save_baseline = None;
+    let mut tolerance = 10.0;
+    let mut generate = false;
+    let mut size = None;
+    let mut scale = 1;
+    let mut seed = 0;
+    let mut output = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--inputs" => inputs = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--ledger" => ledger = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--record" => record = true,
+            "--format" => format = next_value(&mut args, &arg)?.parse()?,
+            "--bench" => bench = true,
+            "--runs" => runs = next_value(&mut args, &arg)?.parse()?,
+            "--baseline" => baseline = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--save-baseline" => save_baseline = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            "--tolerance" => tolerance = next_value(&mut args, &arg)?.parse()?,
+            "--generate" => generate = true,
+            "--size" => size = Some(next_value(&mut args, &arg)?.parse()?),
+            "--scale" => scale = next_value(&mut args, &arg)?.parse()?,
+            "--seed" => seed = next_value(&mut args, &arg)?.parse()?,
+            "--output" => output = Some(PathBuf::from(next_value(&mut args, &arg)?)),
+            flag if flag.starts_with("--") => bail!("Unknown option {:?}\n{}", flag, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+
+    if runs == 0 {
+        bail!("Benchmarks need at least one run\n{}", USAGE);
+    }
+
+    let mut positional = positional.into_iter();
+
+    let target = match positional.next().as_deref() {
+        Some("all") => Target::All,
+        Some(day) => Target::Day(
+            day.parse()
+                .with_context(|| format!("Invalid day {:?}\n{}", day, USAGE))?,
+        ),
+        None => bail!("{}", USAGE),
+    };
+
+    let mut parts = Parts::Both;
+    let mut input = None;
+
+    for arg in positional {
+        match (&target, arg.parse::<Parts>()) {
+            (Target::All, _) if inputs.is_none() => inputs = Some(PathBuf::from(arg)),
+            (Target::Day(_), Ok(selected)) if input.is_none() => parts = selected,
+            (Target::Day(_), _) if input.is_none() => input = Some(InputSource::from_arg(&arg)),
+            _ => bail!("Unexpected argument {:?}\n{}", arg, USAGE),
+        }
+    }
+
+    Ok(Options {
+        target,
+        parts,
+        input,
+        inputs: inputs.unwrap_or_else(inputs_directory),
+        ledger: ledger.unwrap_or_else(ledger_path),
+        record,
+        format,
+        bench,
+        runs,
+        baseline,
+        save_baseline,
+        tolerance,
+        generate,
+        size,
+        scale,
+        seed,
+        output,
+    })
+}
+
+fn check_answers(
+    ledger: &mut Ledger,
+    answers: &[Answer],
+    input: &str,
+    record: bool,
+) -> Vec<Verdict> {
+    let hash = hash_input(input);
+
+    answers
+        .iter()
+        .map(|answer| {
+            let verdict = ledger.check(answer, hash);
+
+            if record {
+                ledger.record(answer, hash);
+            }
+
+            verdict
+        })
+        .collect()
+}
+
+fn describe(verdict: &Verdict, record: bool) -> String {
+    match verdict {
+        Verdict::Unknown if record => " (recorded)".to_string(),
+        Verdict::Unknown => String::new(),
+        Verdict::Correct => " (correct)".to_string(),
+        Verdict::Incorrect(expected) if record => {
+            format!(" (recorded, previously {})", expected)
+        }
+        Verdict::Incorrect(expected) => format!(" (differs from recorded answer {})", expected),
+    }
+}
+
+fn count_mismatches(verdicts: &[Verdict], record: bool) -> usize {
+    if record {
+        return 0;
+    }
+
+    verdicts
+        .iter()
+        .filter(|verdict| matches!(verdict, Verdict::Incorrect(_)))
+        .count()
+}
+
+fn finish(ledger: &Ledger, mismatches: usize, record: bool) -> anyhow::Result<()> {
+    if record {
+        ledger.save()?;
+    }
+
+    if mismatches > 0 {
+        bail!("{} answer(s) differ from the ledger", mismatches);
+    }
+
+    Ok(())
+}
+
+fn run_day(day: u8, options: &Options) -> anyhow::Result<()> {
+    let input = options.source(day).load(day)?;
+    let mut ledger = Ledger::load(&options.ledger)?;
+
+    let answers = get_day(day)?.run(&input, options.parts)?;
+    let verdicts = check_answers(&mut ledger, &answers, &input, options.record);
+
+    match options.format {
+        Format::Text => {
+            for (answer, verdict) in answers.iter().zip(&verdicts) {
+                println!(
+                    "{}: {}{}",
+                    answer.label,
+                    answer.answer,
+                    describe(verdict, options.record)
+                );
+            }
+        }
+        Format::Json => println!("{}", json_records(&answers)),
+    }
+
+    finish(
+        &ledger,
+        count_mismatches(&verdicts, options.record),
+        options.record,
+    )
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.len()).collect();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let format_row = |row: Vec<&str>| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(index, (cell, width))| match index {
+                0 => format!("{:>width$}", cell, width = width),
+                _ => format!("{:<width$}", cell, width = width),
+            })
+            .collect();
+
+        cells.join(" | ").trim_end().to_string()
+    };
+
+    println!("{}", format_row(header.to_vec()));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-")
+    );
+
+    for row in rows {
+        println!(
+            "{}",
+            format_row(row.iter().map(|cell| cell.as_str()).collect())
+        );
+    }
+}
+
+fn run_all(options: &Options) -> anyhow::Result<()> {
+    let source = InputSource::Directory(options.inputs.clone());
+    let mut ledger = Ledger::load(&options.ledger)?;
+    let mut mismatches = 0;
+    let mut failures = 0;
+    let mut records = vec![];
+    let mut rows = vec![];
+
+    for runner in days() {
+        let day = runner.day();
+
+        let results = source.load(day).and_then(|input| {
+            let answers = runner.run(&input, Parts::Both)?;
+            let verdicts = check_answers(&mut ledger, &answers, &input, options.record);
+            Ok((answers, verdicts))
+        });
+
+        match results {
+            Ok((answers, verdicts)) => {
+                if options.format == Format::Text {
+                    for (answer, verdict) in answers.iter().zip(&verdicts) {
+                        println!(
+                            "Day {} - {}: {}{}",
+                            day,
+                            answer.label,
+                            answer.answer,
+                            describe(verdict, options.record)
+                        );
+                    }
+                }
+
+                let day_mismatches = count_mismatches(&verdicts, options.record);
+                mismatches += day_mismatches;
+
+                let status = if options.record {
+                    "recorded"
+                } else if day_mismatches > 0 {
+                    "MISMATCH"
+                } else if verdicts
+                    .iter()
+                    .all(|verdict| matches!(verdict, Verdict::Correct))
+                {
+                    "ok"
+                } else {
+                    "-"
+                };
+
+                rows.push(vec![
+                    day.to_string(),
+                    answers[0].answer.clone(),
+                    answers[1].answer.clone(),
+                    status.to_string(),
+                ]);
+                records.extend(answers.iter().map(json_record));
+            }
+            Err(error) => {
+                eprintln!("Day {} failed: {:#}", day, error);
+                failures += 1;
+                records.push(json_error(day, &format!("{:#}", error)));
+                rows.push(vec![
+                    day.to_string(),
+                    "error".to_string(),
+                    "error".to_string(),
+                    "-".to_string(),
+                ]);
+            }
+        }
+    }
+
+    match options.format {
+        Format::Text => {
+            println!();
+            print_table(&["Day", "Part one", "Part two", "Ledger"], &rows);
+        }
+        Format::Json => println!("{}", json_array(&records)),
+    }
+
+    finish(&ledger, mismatches, options.record)?;
+
+    if failures > 0 {
+        bail!("{} day(s) failed", failures);
+    }
+
+    Ok(())
+}
+
+fn run_bench(options: &Options) -> anyhow::Result<()> {
+    if options.format != Format::Text {
+        bail!("Benchmarks can only be printed as text");
+    }
+
+    let runners = match options.target {
+        Target::Day(day) => vec![get_day(day)?],
+        Target::All => days(),
+    };
+
+    let mut benchmarks: Vec<Benchmark> = vec![];
+    let mut failures = 0;
+
+    for runner in runners {
+        let day = runner.day();
+
+        match options
+            .source(day)
+            .load(day)
+            .and_then(|input| runner.bench(&input, options.runs))
+        {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(error) if matches!(options.target, Target::All) => {
+                eprintln!("Day {} failed: {:#}", day, error);
+                failures += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    let baseline = match &options.baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+
+    let mut regressions = 0;
+    let mut rows = vec![];
+
+    for benchmark in &benchmarks {
+        for (phase, stats) in PHASES.iter().zip(&benchmark.phases) {
+            let mut row = vec![
+                benchmark.day.to_string(),
+                phase.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            ];
+
+            if let Some(baseline) = &baseline {
+                match baseline.get(benchmark.day, phase) {
+                    Some(previous) => {
+                        let change = change(previous, stats.median);
+                        let regressed = change > options.tolerance;
+
+                        if regressed {
+                            regressions += 1;
+                        }
+
+                        row.push(format!("{:.2?}", previous));
+                        row.push(format!(
+                            "{:+.1}%{}",
+                            change,
+                            if regressed { " REGRESSION" } else { "" }
+                        ));
+                    }
+                    None => row.extend(["-".to_string(), "-".to_string()]),
+                }
+            }
+
+            rows.push(row);
+        }
+    }
+
+    let mut header = vec!["Day", "Phase", "Min", "Median", "Max"];
+    if baseline.is_some() {
+        header.extend(["Baseline", "Change"]);
+    }
+
+    print_table(&header, &rows);
+
+    if failures > 0 {
+        bail!("{} day(s) failed", failures);
+    }
+
+    if let Some(path) = &options.save_baseline {
+        Baseline::new(&benchmarks).save(path)?;
+    }
+
+    if regressions > 0 {
+        bail!(
+            "{} phase(s) are more than {}% slower than the baseline",
+            regressions,
+            options.tolerance
+        );
+    }
+
+    Ok(())
+}
+
+fn run_generate(options: &Options) -> anyhow::Result<()> {
+    let size = |day| {
+        options
+            .size
+            .unwrap_or_else(|| scaled_size(day, options.scale))
+    };
+
+    match options.target {
+        Target::Day(day) => {
+            let mut stdout = BufWriter::new(io::stdout().lock());
+            generate_to(day, size(day), options.seed, &mut stdout)?;
+            stdout.flush()?;
+        }
+        Target::All => {
+            let Some(output) = &options.output else {
+                bail!(
+                    "Generating every day needs an --output directory\n{}",
+                    USAGE
+                );
+            };
+
+            for runner in days() {
+                let day = runner.day();
+                let path = day_path(output, day);
+
+                fs::create_dir_all(path.parent().unwrap())?;
+                let file = fs::File::create(&path)
+                    .with_context(|| format!("Failed to write {:?}", path))?;
+
+                let mut writer = BufWriter::new(file);
+                generate_to(day, size(day), options.seed, &mut writer)?;
+                writer.flush()?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let options = parse_args(env::args().skip(1).collect())?;
+
+    match options.target {
+        _ if options.generate => run_generate(&options),
+        _ if options.bench => run_bench(&options),
+        Target::Day(day) => run_day(day, &options),
+        Target::All => run_all(&options),
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+
+use crate::Solution;
+
+pub const PHASES: [&str; 3] = ["parse", "part_one", "part_two"];
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Benchmark {
+    pub day: u8,
+    pub phases: [Stats; 3],
+}
+
+fn time<T>(function: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<Duration> {
+    let start = Instant::now();
+    black_box(function()?);
+    Ok(start.elapsed())
+}
+
+pub fn measure<T>(
+    runs: usize,
+    mut function: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<Stats> {
+    if runs == 0 {
+        bail!("Benchmarks need at least one run");
+    }
+
+    let mut samples = vec![];
+
+    for _run in 0..runs {
+        samples.push(time(&mut function)?);
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+pub fn compare<T: PartialEq>(
+    runs: usize,
+    mut before: impl FnMut() -> anyhow::Result<T>,
+    mut after: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<[Stats; 2]> {
+    if before()? != after()? {
+        bail!("The implementations being compared disagree");
+    }
+
+    Ok([measure(runs, &mut before)?, measure(runs, &mut after)?])
+}
+
+pub fn speedup(before: &Stats, after: &Stats) -> f64 {
+    before.median.as_secs_f64() / after.median.as_secs_f64()
+}
+
+pub fn bench<S: Solution>(input: &str, runs: usize) -> anyhow::Result<Benchmark> {
+    if runs == 0 {
+        bail!("Benchmarks need at least one run");
+    }
+
+    let parsed = S::parse(input)?;
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+
+    for _run in 0..runs {
+        samples[0].push(time(|| S::parse(black_box(input)))?);
+        samples[1].push(time(|| S::part_one(black_box(&parsed)))?);
+        samples[2].push(time(|| S::part_two(black_box(&parsed)))?);
+    }
+
+    Ok(Benchmark {
+        day: S::DAY,
+        phases: samples.map(Stats::from_samples),
+    })
+}
+
+pub struct Baseline {
+    medians: BTreeMap<(u8, String), Duration>,
+}
+
+impl Baseline {
+    pub fn new(benchmarks: &[Benchmark]) -> Self {
+        let mut medians = BTreeMap::new();
+
+        for benchmark in benchmarks {
+            for (phase, stats) in PHASES.iter().zip(&benchmark.phases) {
+                medians.insert((benchmark.day, phase.to_string()), stats.median);
+            }
+        }
+
+        Baseline { medians }
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline {:?}", path))?;
+        let mut medians = BTreeMap::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let fields: Vec<&str> = line.split('\t').collect();
+
+            let [day, phase, nanos] = fields[..] else {
+                bail!(
+                    "Malformed baseline entry on line {} of {:?}",
+                    index + 1,
+                    path
+                );
+            };
+
+            medians.insert(
+                (day.parse()?, phase.to_string()),
+                Duration::from_nanos(nanos.parse()?),
+            );
+        }
+
+        Ok(Baseline { medians })
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut contents = String::new();
+
+        for ((day, phase), median) in &self.medians {
+            contents += &format!("{}\t{}\t{}\n", day, phase, median.as_nanos());
+        }
+
+        fs::write(path, contents).with_context(|| format!("Failed to write baseline {:?}", path))
+    }
+
+    pub fn get(&self, day: u8, phase: &str) -> Option<Duration> {
+        self.medians.get(&(day, phase.to_string())).copied()
+    }
+}
+
+pub fn change(baseline: Duration, current: Duration) -> f64 {
+    (current.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64() * 100.0
+}
@@ -0,0 +1,200 @@
+use std::{cmp::Reverse, collections::BinaryHeap, str::FromStr};
+
+use anyhow::bail;
+
+pub const EXACT_LIMIT: usize = 20;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Method {
+    Exact,
+    Greedy,
+    Auto,
+}
+
+impl Method {
+    pub fn resolve(self, items: usize) -> Method {
+        match self {
+            Method::Auto if items <= EXACT_LIMIT => Method::Exact,
+            Method::Auto => Method::Greedy,
+            method => method,
+        }
+    }
+}
+
+impl FromStr for Method {
+    type Err = anyhow::Error;
+
+    fn from_str(method: &str) -> anyhow::Result<Self> {
+        match method {
+            "exact" => Ok(Method::Exact),
+            "greedy" => Ok(Method::Greedy),
+            "auto" => Ok(Method::Auto),
+            _ => bail!(
+                "Unknown method {:?}, expected exact, greedy or auto",
+                method
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Partition {
+    pub bins: Vec<Vec<u32>>,
+    pub loads: Vec<u64>,
+}
+
+impl Partition {
+    fn empty(elves: usize) -> Self {
+        Partition {
+            bins: vec![vec![]; elves],
+            loads: vec![0; elves],
+        }
+    }
+
+    fn add(&mut self, elf: usize, item: u32) {
+        self.bins[elf].push(item);
+        self.loads[elf] += item as u64;
+    }
+
+    pub fn max_load(&self) -> u64 {
+        self.loads.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn min_load(&self) -> u64 {
+        self.loads.iter().copied().min().unwrap_or(0)
+    }
+
+    pub fn imbalance(&self) -> u64 {
+        self.max_load() - self.min_load()
+    }
+}
+
+fn check_elves(elves: usize) -> anyhow::Result<()> {
+    if elves == 0 {
+        bail!("Snacks can only be balanced across at least one elf");
+    }
+
+    Ok(())
+}
+
+pub fn lower_bound(items: &[u32], elves: usize) -> anyhow::Result<u64> {
+    check_elves(elves)?;
+
+    let total: u64 = items.iter().map(|item| *item as u64).sum();
+    let largest = items.iter().copied().max().unwrap_or(0) as u64;
+
+    Ok(total.div_ceil(elves as u64).max(largest))
+}
+
+fn sorted_descending(items: &[u32]) -> Vec<u32> {
+    let mut items = items.to_vec();
+    items.sort_by(|a, b| b.cmp(a));
+    items
+}
+
+pub fn greedy(items: &[u32], elves: usize) -> Partition {
+    let mut partition = Partition::empty(elves);
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> =
+        (0..elves).map(|elf| Reverse((0, elf))).collect();
+
+    for item in sorted_descending(items) {
+        let Some(Reverse((load, elf))) = heap.pop() else {
+            break;
+        };
+
+        partition.add(elf, item);
+        heap.push(Reverse((load + item as u64, elf)));
+    }
+
+    partition
+}
+
+struct Search {
+    items: Vec<u32>,
+    lower_bound: u64,
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best_max: u64,
+    best_assignment: Vec<usize>,
+}
+
+impl Search {
+    fn assign(&mut self, index: usize, current_max: u64) {
+        if self.best_max == self.lower_bound {
+            return;
+        }
+
+        if index == self.items.len() {
+            if current_max < self.best_max {
+                self.best_max = current_max;
+                self.best_assignment = self.assignment.clone();
+            }
+            return;
+        }
+
+        let item = self.items[index] as u64;
+        let mut tried: Vec<u64> = vec![];
+
+        for elf in 0..self.loads.len() {
+            let load = self.loads[elf] + item;
+
+            if load >= self.best_max || tried.contains(&self.loads[elf]) {
+                continue;
+            }
+
+            tried.push(self.loads[elf]);
+
+            self.loads[elf] = load;
+            self.assignment[index] = elf;
+            self.assign(index + 1, current_max.max(load));
+            self.loads[elf] -= item;
+        }
+    }
+}
+
+pub fn exact(items: &[u32], elves: usize) -> anyhow::Result<Partition> {
+    check_elves(elves)?;
+
+    if items.len() > EXACT_LIMIT {
+        bail!(
+            "Exact balancing is limited to {} items, found {}",
+            EXACT_LIMIT,
+            items.len()
+        );
+    }
+
+    let items = sorted_descending(items);
+    let greedy = greedy(&items, elves);
+
+    let mut search = Search {
+        lower_bound: lower_bound(&items, elves)?,
+        loads: vec![0; elves],
+        assignment: vec![0; items.len()],
+        best_max: greedy.max_load(),
+        best_assignment: vec![],
+        items,
+    };
+
+    search.assign(0, 0);
+
+    if search.best_assignment.is_empty() {
+        return Ok(greedy);
+    }
+
+    let mut partition = Partition::empty(elves);
+    for (item, elf) in search.items.iter().zip(&search.best_assignment) {
+        partition.add(*elf, *item);
+    }
+
+    Ok(partition)
+}
+
+pub fn balance(items: &[u32], elves: usize, method: Method) -> anyhow::Result<Partition> {
+    match method.resolve(items.len()) {
+        Method::Greedy => {
+            check_elves(elves)?;
+            Ok(greedy(items, elves))
+        }
+        _ => exact(items, elves),
+    }
+}
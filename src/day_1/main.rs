@@ -1,35 +1,126 @@
-static INPUT: &str = include_str!("input.txt");
+use std::env;
+
+use advent_of_code::{
+    day_1::{
+        balance::{balance, lower_bound, Method},
+        elf_totals, rank,
+        stats::Summary,
+        stream_top_elves, top_elves, Day1,
+    },
+    input::InputSource,
+    Solution,
+};
+use anyhow::{anyhow, bail};
+
+const USAGE: &str = "Usage: day_1 [input|-] [--top <n>] [--rank <elf>] [--stats]
+             [--balance <elves>] [--method <exact|greedy|auto>]";
+
+fn next_number(args: &mut impl Iterator<Item = String>) -> anyhow::Result<usize> {
+    Ok(args.next().ok_or_else(|| anyhow!(USAGE))?.parse()?)
+}
 
 fn main() -> anyhow::Result<()> {
-    let mut num: u32 = 0;
-    let mut totals: Vec<u32> = vec![];
-
-    for line in INPUT.lines() {
-        let line = line.trim();
-
-        if line == "" {
-            totals.push(num);
-            num = 0;
-        } else {
-            let line: u32 = line.parse()?;
-            num += line;
+    let mut source = InputSource::default();
+    let mut top = None;
+    let mut rank_of = None;
+    let mut stats = false;
+    let mut balance_across = None;
+    let mut method = Method::Auto;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => top = Some(next_number(&mut args)?),
+            "--rank" => rank_of = Some(next_number(&mut args)?),
+            "--stats" => stats = true,
+            "--balance" => balance_across = Some(next_number(&mut args)?),
+            "--method" => method = args.next().ok_or_else(|| anyhow!(USAGE))?.parse()?,
+            flag if flag.starts_with("--") => bail!(USAGE),
+            _ => source = InputSource::from_arg(&arg),
         }
     }
 
-    totals.push(num);
-    totals.sort();
+    let count = top.unwrap_or(0).max(3);
+
+    let (elves, leaders) = match rank_of.is_some() || stats || balance_across.is_some() {
+        true => {
+            let elves = Day1::parse(&source.load(Day1::DAY)?)?;
+            let leaders = top_elves(&elves, count);
+            (Some(elves), leaders)
+        }
+        false => (None, stream_top_elves(source.reader(Day1::DAY)?, count)?),
+    };
 
-    let totals_last = totals.len() - 1;
+    let sum = |count: usize| -> u64 { leaders.iter().take(count).map(|elf| elf.total).sum() };
 
-    println!(
-        "Highest total calories: {}",
-        totals[totals_last]
-    );
+    println!("{}: {}", Day1::PART_ONE, sum(1));
+    println!("{}: {}", Day1::PART_TWO, sum(3));
 
-    println!(
-        "Total of top 3: {}",
-        totals[totals_last] + totals[totals_last - 1] + totals[totals_last - 2]
-    );
+    if let Some(top) = top {
+        if leaders.len() < top {
+            println!("\nOnly {} elves, showing all of them", leaders.len());
+        }
+
+        println!("\nTop {} elves:", top.min(leaders.len()));
+
+        for (position, elf) in leaders.iter().take(top).enumerate() {
+            println!("{:>4}. Elf {}: {}", position + 1, elf.index + 1, elf.total);
+        }
+
+        println!("Total of top {}: {}", top.min(leaders.len()), sum(top));
+    }
+
+    if let (Some(elf), Some(elves)) = (rank_of, &elves) {
+        let Some(rank) = elf.checked_sub(1).and_then(|index| rank(elves, index)) else {
+            bail!(
+                "Elf {} does not exist, there are {} elves",
+                elf,
+                elves.len()
+            );
+        };
+
+        println!(
+            "\nElf {} is ranked {} of {} with {} calories",
+            elf,
+            rank,
+            elves.len(),
+            elf_totals(elves)[elf - 1].total
+        );
+    }
+
+    if let (true, Some(elves)) = (stats, &elves) {
+        if let Some(summary) = Summary::new(elves) {
+            println!("\n{}", summary);
+        }
+    }
+
+    if let (Some(count), Some(elves)) = (balance_across, &elves) {
+        let items: Vec<u32> = elves.concat();
+        let partition = balance(&items, count, method)?;
+
+        println!(
+            "\nBalanced {} items across {} elves ({:?}):",
+            items.len(),
+            count,
+            method.resolve(items.len())
+        );
+
+        for (elf, (load, bin)) in partition.loads.iter().zip(&partition.bins).enumerate() {
+            println!("  Elf {}: {} ({} items)", elf + 1, load, bin.len());
+        }
+
+        println!(
+            "Maximum load: {} (lower bound {})",
+            partition.max_load(),
+            lower_bound(&items, count)?
+        );
+        println!(
+            "Imbalance: {} ({} to {})",
+            partition.imbalance(),
+            partition.min_load(),
+            partition.max_load()
+        );
+    }
 
     Ok(())
 }
@@ -0,0 +1,162 @@
+pub mod balance;
+pub mod stats;
+
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use crate::{
+    parse::{self, Line},
+    Solution,
+};
+
+pub struct Day1;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub total: u64,
+}
+
+pub struct TopTotals {
+    count: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopTotals {
+    pub fn new(count: usize) -> Self {
+        TopTotals {
+            count,
+            heap: BinaryHeap::with_capacity(count + 1),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(index))));
+
+        if self.heap.len() > self.count {
+            self.heap.pop();
+        }
+    }
+
+    pub fn into_sorted(self) -> Vec<ElfTotal> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| ElfTotal { index, total })
+            .collect()
+    }
+}
+
+pub fn elf_totals(elves: &[Vec<u32>]) -> Vec<ElfTotal> {
+    elves
+        .iter()
+        .enumerate()
+        .map(|(index, items)| ElfTotal {
+            index,
+            total: items.iter().map(|item| *item as u64).sum(),
+        })
+        .collect()
+}
+
+pub fn top_elves(elves: &[Vec<u32>], count: usize) -> Vec<ElfTotal> {
+    let mut top = TopTotals::new(count);
+
+    for elf in elf_totals(elves) {
+        top.push(elf.index, elf.total);
+    }
+
+    top.into_sorted()
+}
+
+pub fn rank(elves: &[Vec<u32>], index: usize) -> Option<usize> {
+    let totals = elf_totals(elves);
+    let total = totals.get(index)?.total;
+
+    Some(totals.iter().filter(|elf| elf.total > total).count() + 1)
+}
+
+pub fn stream_top_elves(mut reader: impl BufRead, count: usize) -> anyhow::Result<Vec<ElfTotal>> {
+    let mut top = TopTotals::new(count);
+    let mut index = 0;
+    let mut total: u64 = 0;
+    let mut items = 0;
+    let mut buffer = String::new();
+    let mut number = 0;
+
+    loop {
+        buffer.clear();
+
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+
+        number += 1;
+
+        let line = Line {
+            day: Day1::DAY,
+            number,
+            text: buffer.trim_end_matches(['\n', '\r']),
+        };
+        let text = line.text.trim();
+
+        if text.is_empty() {
+            if items > 0 {
+                top.push(index, total);
+                index += 1;
+            }
+
+            total = 0;
+            items = 0;
+        } else {
+            total += line.parse::<u32>(text)? as u64;
+            items += 1;
+        }
+    }
+
+    if items > 0 {
+        top.push(index, total);
+    }
+
+    Ok(top.into_sorted())
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const PART_ONE: &'static str = "Highest total calories";
+    const PART_TWO: &'static str = "Total of top 3";
+
+    type Input = Vec<Vec<u32>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut items: Vec<u32> = vec![];
+        let mut elves: Vec<Vec<u32>> = vec![];
+
+        for line in parse::lines(Self::DAY, input) {
+            let text = line.text.trim();
+
+            if text.is_empty() {
+                if !items.is_empty() {
+                    elves.push(items);
+                    items = vec![];
+                }
+            } else {
+                items.push(line.parse(text)?);
+            }
+        }
+
+        if !items.is_empty() {
+            elves.push(items);
+        }
+
+        Ok(elves)
+    }
+
+    fn part_one(elves: &Self::Input) -> anyhow::Result<u64> {
+        Ok(top_elves(elves, 1).iter().map(|elf| elf.total).sum())
+    }
+
+    fn part_two(elves: &Self::Input) -> anyhow::Result<u64> {
+        Ok(top_elves(elves, 3).iter().map(|elf| elf.total).sum())
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::day_1::{elf_totals, ElfTotal};
+
+pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+const BUCKETS: u64 = 10;
+const BAR_WIDTH: usize = 40;
+
+pub struct Bucket {
+    pub min: u64,
+    pub max: u64,
+    pub count: usize,
+}
+
+pub struct Summary {
+    pub elves: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub percentiles: Vec<(u8, u64)>,
+    pub histogram: Vec<Bucket>,
+    pub items_per_elf: BTreeMap<usize, usize>,
+    pub outliers: Vec<ElfTotal>,
+}
+
+fn percentile(sorted: &[u64], percent: u8) -> u64 {
+    let rank = (percent as f64 / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn histogram(sorted: &[u64]) -> Vec<Bucket> {
+    let min = sorted[0];
+    let max = sorted[sorted.len() - 1];
+    let width = ((max - min) / BUCKETS + 1).max(1);
+
+    let mut buckets: Vec<Bucket> = (0..BUCKETS)
+        .map(|bucket| Bucket {
+            min: min + bucket * width,
+            max: min + (bucket + 1) * width - 1,
+            count: 0,
+        })
+        .take_while(|bucket| bucket.min <= max)
+        .collect();
+
+    for total in sorted {
+        buckets[((total - min) / width) as usize].count += 1;
+    }
+
+    buckets
+}
+
+impl Summary {
+    pub fn new(elves: &[Vec<u32>]) -> Option<Self> {
+        let totals = elf_totals(elves);
+
+        let mut sorted: Vec<u64> = totals.iter().map(|elf| elf.total).collect();
+        sorted.sort();
+
+        let count = sorted.len();
+        let min = *sorted.first()?;
+        let max = sorted[count - 1];
+
+        let mean = sorted.iter().sum::<u64>() as f64 / count as f64;
+        let median = match count % 2 {
+            0 => (sorted[count / 2 - 1] + sorted[count / 2]) as f64 / 2.0,
+            _ => sorted[count / 2] as f64,
+        };
+        let variance = sorted
+            .iter()
+            .map(|total| (*total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let mut items_per_elf = BTreeMap::new();
+        for items in elves {
+            *items_per_elf.entry(items.len()).or_insert(0) += 1;
+        }
+
+        let first_quartile = percentile(&sorted, 25) as f64;
+        let third_quartile = percentile(&sorted, 75) as f64;
+        let fence = (third_quartile - first_quartile) * 1.5;
+
+        let outliers = totals
+            .into_iter()
+            .filter(|elf| {
+                (elf.total as f64) < first_quartile - fence
+                    || (elf.total as f64) > third_quartile + fence
+            })
+            .collect();
+
+        Some(Summary {
+            elves: count,
+            min,
+            max,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            percentiles: PERCENTILES
+                .iter()
+                .map(|percent| (*percent, percentile(&sorted, *percent)))
+                .collect(),
+            histogram: histogram(&sorted),
+            items_per_elf,
+            outliers,
+        })
+    }
+}
+
+fn bar(count: usize, largest: usize) -> String {
+    match count {
+        0 => String::new(),
+        _ => format!(
+            " {}",
+            "#".repeat((count * BAR_WIDTH).div_ceil(largest.max(1)))
+        ),
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Min: {}", self.min)?;
+        writeln!(f, "Max: {}", self.max)?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        writeln!(f, "Standard deviation: {:.1}", self.std_dev)?;
+
+        writeln!(f, "\nPercentiles:")?;
+        for (percent, total) in &self.percentiles {
+            writeln!(f, "  p{:<3} {}", percent, total)?;
+        }
+
+        writeln!(f, "\nTotals:")?;
+        let largest = self.histogram.iter().map(|bucket| bucket.count).max();
+        for bucket in &self.histogram {
+            writeln!(
+                f,
+                "  {:>7} - {:<7} {:>6}{}",
+                bucket.min,
+                bucket.max,
+                bucket.count,
+                bar(bucket.count, largest.unwrap_or(0))
+            )?;
+        }
+
+        writeln!(f, "\nItems per elf:")?;
+        let largest = self.items_per_elf.values().max();
+        for (items, count) in &self.items_per_elf {
+            writeln!(
+                f,
+                "  {:>3} {:>6}{}",
+                items,
+                count,
+                bar(*count, *largest.unwrap_or(&0))
+            )?;
+        }
+
+        write!(f, "\nOutliers:")?;
+        if self.outliers.is_empty() {
+            write!(f, " none")?;
+        }
+        for elf in &self.outliers {
+            write!(f, "\n  Elf {}: {}", elf.index + 1, elf.total)?;
+        }
+
+        Ok(())
+    }
+}
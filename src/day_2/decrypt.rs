@@ -0,0 +1,149 @@
+use std::cmp::Reverse;
+
+use anyhow::bail;
+
+use crate::day_2::{game::Outcome, rules::Rules};
+
+pub const MAX_MOVES: usize = 7;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Meaning {
+    Moves(Vec<usize>),
+    Outcomes(Vec<Outcome>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Interpretation {
+    pub meaning: Meaning,
+    pub score: u32,
+}
+
+pub fn permutations(count: usize) -> Vec<Vec<usize>> {
+    if count == 0 {
+        return vec![vec![]];
+    }
+
+    let mut permutations = vec![];
+
+    for first in 0..count {
+        for rest in permutations_without(count, first) {
+            let mut permutation = vec![first];
+            permutation.extend(rest);
+            permutations.push(permutation);
+        }
+    }
+
+    permutations
+}
+
+fn permutations_without(count: usize, used: usize) -> Vec<Vec<usize>> {
+    permutations(count - 1)
+        .into_iter()
+        .map(|permutation| {
+            permutation
+                .into_iter()
+                .map(|index| if index >= used { index + 1 } else { index })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn meanings(rules: &Rules) -> anyhow::Result<Vec<Meaning>> {
+    let moves = rules.game().moves();
+
+    if moves > MAX_MOVES {
+        bail!(
+            "Decrypting tries every permutation of the responses, so it is limited to {} moves, not {}",
+            MAX_MOVES,
+            moves
+        );
+    }
+
+    let mut meanings: Vec<Meaning> = permutations(moves)
+        .into_iter()
+        .map(Meaning::Moves)
+        .collect();
+
+    if moves == Outcome::ALL.len() {
+        meanings.extend(permutations(moves).into_iter().map(|permutation| {
+            Meaning::Outcomes(
+                permutation
+                    .into_iter()
+                    .map(|index| Outcome::ALL[index])
+                    .collect(),
+            )
+        }));
+    }
+
+    Ok(meanings)
+}
+
+impl Meaning {
+    pub fn part_one(rules: &Rules) -> Meaning {
+        Meaning::Moves((0..rules.game().moves()).collect())
+    }
+
+    pub fn part_two(rules: &Rules) -> Option<Meaning> {
+        let outcomes = (0..rules.response.len())
+            .map(|response| rules.get_outcome(response))
+            .collect::<Option<Vec<Outcome>>>()?;
+
+        Some(Meaning::Outcomes(outcomes))
+    }
+
+    pub fn get_action(&self, rules: &Rules, column: usize, opponent: usize) -> usize {
+        match self {
+            Meaning::Moves(moves) => moves[column],
+            Meaning::Outcomes(outcomes) => rules.game().get_response(opponent, outcomes[column]),
+        }
+    }
+
+    pub fn get_total(&self, rules: &Rules, rounds: &[(usize, usize)]) -> u32 {
+        let mut total = 0;
+
+        for (opponent, column) in rounds {
+            let you = self.get_action(rules, *column, *opponent);
+            total += rules.get_score(you, *opponent);
+        }
+
+        total
+    }
+
+    pub fn describe(&self, rules: &Rules) -> String {
+        let names: Vec<String> = match self {
+            Meaning::Moves(moves) => moves
+                .iter()
+                .map(|index| rules.game().get_name(*index))
+                .collect(),
+            Meaning::Outcomes(outcomes) => outcomes
+                .iter()
+                .map(|outcome| format!("{:?}", outcome))
+                .collect(),
+        };
+
+        let pairs: Vec<String> = rules
+            .response
+            .iter()
+            .zip(&names)
+            .map(|(symbol, name)| format!("{}={}", symbol, name))
+            .collect();
+
+        pairs.join(" ")
+    }
+}
+
+pub fn interpretations(
+    rules: &Rules,
+    rounds: &[(usize, usize)],
+) -> anyhow::Result<Vec<Interpretation>> {
+    let mut interpretations: Vec<Interpretation> = meanings(rules)?
+        .into_iter()
+        .map(|meaning| Interpretation {
+            score: meaning.get_total(rules, rounds),
+            meaning,
+        })
+        .collect();
+
+    interpretations.sort_by_key(|interpretation| Reverse(interpretation.score));
+    Ok(interpretations)
+}
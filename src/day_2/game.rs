@@ -0,0 +1,80 @@
+use anyhow::bail;
+
+pub const ROCK_PAPER_SCISSORS: [&str; 3] = ["Rock", "Paper", "Scissors"];
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: [&str; 5] =
+    ["Rock", "Spock", "Paper", "Lizard", "Scissors"];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn get_score(&self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Game {
+    moves: usize,
+}
+
+impl Game {
+    pub const ROCK_PAPER_SCISSORS: Game = Game { moves: 3 };
+
+    pub fn new(moves: usize) -> anyhow::Result<Self> {
+        if moves < 3 || moves.is_multiple_of(2) {
+            bail!(
+                "A cyclic game needs an odd number of moves, at least 3, not {}",
+                moves
+            );
+        }
+
+        Ok(Game { moves })
+    }
+
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    pub fn get_outcome(&self, you: usize, opponent: usize) -> Outcome {
+        match (you + self.moves - opponent) % self.moves {
+            0 => Outcome::Draw,
+            difference if difference <= self.moves / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    pub fn get_response(&self, opponent: usize, outcome: Outcome) -> usize {
+        match outcome {
+            Outcome::Lose => (opponent + self.moves - 1) % self.moves,
+            Outcome::Draw => opponent,
+            Outcome::Win => (opponent + 1) % self.moves,
+        }
+    }
+
+    pub fn get_name(&self, index: usize) -> String {
+        match self.moves {
+            3 => ROCK_PAPER_SCISSORS[index].to_string(),
+            5 => ROCK_PAPER_SCISSORS_LIZARD_SPOCK[index].to_string(),
+            _ => format!("Move {}", index + 1),
+        }
+    }
+
+    pub fn get_value(&self, you: usize) -> u32 {
+        you as u32 + 1
+    }
+
+    pub fn get_score(&self, you: usize, opponent: usize) -> u32 {
+        self.get_value(you) + self.get_outcome(you, opponent).get_score()
+    }
+}
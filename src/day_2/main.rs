@@ -1,104 +1,181 @@
-static INPUT: &str = include_str!("input.txt");
+use std::{env, fs, path::PathBuf};
+
+use advent_of_code::{
+    day_2::{
+        decrypt::{interpretations, Meaning},
+        rules::Rules,
+        simulate::{play, to_csv, Round, Strategy},
+        Day2,
+    },
+    input::InputSource,
+    Solution,
+};
+use anyhow::{anyhow, bail, Context};
+
+const USAGE: &str = "Usage: day_2 [input|-] [--rules <file>] [--opponent <symbols>]
+             [--response <symbols>] [--outcome <lose,draw,win symbols>]
+             [--values <scores>] [--outcomes <lose,draw,win scores>] [--decrypt]
+             [--replay] [--csv <file>] [--against <strategy>]
+
+Strategies: guide, random[:seed], frequency, cycle:<moves such as RPS or rock,spock>";
 
-enum Actions {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-impl Actions {
-    fn from_char(char: &str) -> Actions {
-        match char {
-            "A" => Actions::Rock,
-            "B" => Actions::Paper,
-            "C" => Actions::Scissors,
-
-            "X" => Actions::Rock,
-            "Y" => Actions::Paper,
-            "Z" => Actions::Scissors,
-
-            _ => Actions::Rock,
+fn main() -> anyhow::Result<()> {
+    let mut source = InputSource::default();
+    let mut rules_path = None;
+    let mut overrides = vec![];
+    let mut decrypt = false;
+    let mut replay = false;
+    let mut csv_path = None;
+    let mut strategy = Strategy::Guide;
+    let mut strategy_name = String::new();
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => {
+                rules_path = Some(PathBuf::from(args.next().ok_or_else(|| anyhow!(USAGE))?))
+            }
+            "--opponent" | "--response" | "--outcome" | "--values" | "--outcomes" => {
+                let value = args.next().ok_or_else(|| anyhow!(USAGE))?;
+                overrides.push((arg[2..].to_string(), value));
+            }
+            "--decrypt" => decrypt = true,
+            "--replay" => replay = true,
+            "--csv" => csv_path = Some(args.next().ok_or_else(|| anyhow!(USAGE))?),
+            "--against" => {
+                strategy_name = args.next().ok_or_else(|| anyhow!(USAGE))?;
+                strategy = strategy_name.parse()?;
+            }
+            flag if flag.starts_with("--") => bail!(USAGE),
+            _ => source = InputSource::from_arg(&arg),
         }
     }
 
-    fn get_score(&self, opponent: &Actions) -> u32 {
-        match self {
-            Actions::Rock => match opponent {
-                Actions::Rock => 3,
-                Actions::Paper => 0,
-                Actions::Scissors => 6,
-            },
-            Actions::Paper => match opponent {
-                Actions::Rock => 6,
-                Actions::Paper => 3,
-                Actions::Scissors => 0,
-            },
-            Actions::Scissors => match opponent {
-                Actions::Rock => 0,
-                Actions::Paper => 6,
-                Actions::Scissors => 3,
-            },
-        }
+    let mut rules = match &rules_path {
+        Some(path) => Rules::load(path)?,
+        None => Rules::default(),
+    };
+
+    for (key, value) in &overrides {
+        rules.set(key, value)?;
     }
 
-    fn get_value(&self) -> u32 {
-        match self {
-            Actions::Rock => 1,
-            Actions::Paper => 2,
-            Actions::Scissors => 3,
-        }
+    rules.validate()?;
+
+    let input = source.load(Day2::DAY)?;
+    let rounds = rules.parse_guide(Day2::DAY, &input)?;
+
+    println!("{}: {}", Day2::PART_ONE, rules.action_total(&rounds));
+    match rules.outcome_total(&rounds) {
+        Ok(total) => println!("{}: {}", Day2::PART_TWO, total),
+        Err(error) => println!("{}: skipped. {:#}", Day2::PART_TWO, error),
     }
 
-    fn get_action(&self, opponent: &Actions) -> Actions {
-        match self {
-            Actions::Rock => match opponent {
-                // LOSE
-                Actions::Rock => Actions::Scissors,
-                Actions::Paper => Actions::Rock,
-                Actions::Scissors => Actions::Paper,
-            },
-            Actions::Paper => match opponent {
-                // DRAW
-                Actions::Rock => Actions::Rock,
-                Actions::Paper => Actions::Paper,
-                Actions::Scissors => Actions::Scissors,
-            },
-            Actions::Scissors => match opponent {
-                // WIN
-                Actions::Rock => Actions::Paper,
-                Actions::Paper => Actions::Scissors,
-                Actions::Scissors => Actions::Rock,
-            },
+    if decrypt {
+        let interpretations = interpretations(&rules, &rounds)?;
+        let part_one = Meaning::part_one(&rules);
+        let part_two = Meaning::part_two(&rules);
+
+        let (last, rest) = rules.response.split_last().ok_or_else(|| anyhow!(USAGE))?;
+
+        println!(
+            "\nEvery interpretation of {} and {}:",
+            rest.join(", "),
+            last
+        );
+
+        for (rank, interpretation) in interpretations.iter().enumerate() {
+            let kind = match interpretation.meaning {
+                Meaning::Moves(_) => "moves",
+                Meaning::Outcomes(_) => "outcomes",
+            };
+            let note = if interpretation.meaning == part_one {
+                " (part one)"
+            } else if Some(&interpretation.meaning) == part_two.as_ref() {
+                " (part two)"
+            } else {
+                ""
+            };
+
+            println!(
+                "{:>4}. {:<8} {:<32} {}{}",
+                rank + 1,
+                kind,
+                interpretation.meaning.describe(&rules),
+                interpretation.score,
+                note
+            );
         }
+
+        let best = interpretations.first().map(|best| best.score).unwrap_or(0);
+        let worst = interpretations.last().map(|worst| worst.score).unwrap_or(0);
+
+        println!("Spread: {} ({} to {})", best - worst, worst, best);
     }
-}
 
-fn main() -> anyhow::Result<()> {
-    let mut action_total_score: u32 = 0;
-    let mut outcome_total_score: u32 = 0;
+    if replay || csv_path.is_some() || strategy != Strategy::Guide {
+        let mut games = vec![(
+            "moves",
+            play(&rules, &rounds, &Meaning::part_one(&rules), &strategy)?,
+        )];
 
-    for line in INPUT.lines() {
-        let chars: Vec<&str> = line.trim().split(" ").collect();
+        if let Some(outcomes) = Meaning::part_two(&rules) {
+            games.push(("outcomes", play(&rules, &rounds, &outcomes, &strategy)?));
+        }
 
-        let opponent = Actions::from_char(chars[0]);
-        let you = Actions::from_char(chars[1]);
+        if replay {
+            let game = rules.game();
+
+            for (name, records) in &games {
+                println!("\nReplay of the {} interpretation:", name);
+                println!(
+                    "{:>6}  {:<8}  {:<8}  {:<7}  {:>6}  {:>7}",
+                    "Round", "Opponent", "You", "Outcome", "Points", "Score"
+                );
+
+                for round in records {
+                    println!(
+                        "{:>6}  {:<8}  {:<8}  {:<7}  {:>6}  {:>7}",
+                        round.number,
+                        game.get_name(round.opponent),
+                        game.get_name(round.you),
+                        format!("{:?}", round.outcome),
+                        round.points,
+                        round.running_score
+                    );
+                }
+            }
+        }
 
-        action_total_score += you.get_value() + you.get_score(&opponent);
+        if strategy != Strategy::Guide {
+            println!("\nAgainst {}:", strategy_name);
 
-        let you = you.get_action(&opponent);
+            for (name, records) in &games {
+                println!("{:<9} {}", name, describe(records));
+            }
+        }
 
-        outcome_total_score += you.get_value() + you.get_score(&opponent);
+        if let Some(path) = &csv_path {
+            fs::write(path, to_csv(&rules, &games))
+                .with_context(|| format!("Failed to write {}", path))?;
+        }
     }
 
-    println!(
-        "Action total score: {}",
-        action_total_score
-    );
+    Ok(())
+}
 
-    println!(
-        "Outcome total score: {}",
-        outcome_total_score
-    );
+fn describe(records: &[Round]) -> String {
+    let mut tally = [0; 3];
 
-    Ok(())
+    for round in records {
+        tally[round.outcome as usize] += 1;
+    }
+
+    format!(
+        "{} points ({} won, {} drawn, {} lost)",
+        records.last().map(|round| round.running_score).unwrap_or(0),
+        tally[2],
+        tally[1],
+        tally[0]
+    )
 }
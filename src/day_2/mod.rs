@@ -0,0 +1,100 @@
+pub mod decrypt;
+pub mod game;
+pub mod rules;
+pub mod simulate;
+
+use crate::{
+    day_2::{
+        game::{Game, Outcome},
+        rules::Rules,
+    },
+    Solution,
+};
+
+pub struct Day2;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Actions {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Actions {
+    pub fn from_index(index: usize) -> Actions {
+        match index % 3 {
+            0 => Actions::Rock,
+            1 => Actions::Paper,
+            _ => Actions::Scissors,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            Actions::Rock => 0,
+            Actions::Paper => 1,
+            Actions::Scissors => 2,
+        }
+    }
+
+    pub fn as_outcome(&self) -> Outcome {
+        Outcome::ALL[self.index()]
+    }
+
+    pub fn get_score(&self, opponent: &Actions) -> u32 {
+        Game::ROCK_PAPER_SCISSORS
+            .get_outcome(self.index(), opponent.index())
+            .get_score()
+    }
+
+    pub fn get_value(&self) -> u32 {
+        Game::ROCK_PAPER_SCISSORS.get_value(self.index())
+    }
+
+    pub fn get_action(&self, opponent: &Actions) -> Actions {
+        Actions::from_index(
+            Game::ROCK_PAPER_SCISSORS.get_response(opponent.index(), self.as_outcome()),
+        )
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const PART_ONE: &'static str = "Action total score";
+    const PART_TWO: &'static str = "Outcome total score";
+
+    type Input = Vec<(Actions, Actions)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let rounds = Rules::default().parse_guide(Self::DAY, input)?;
+
+        Ok(rounds
+            .into_iter()
+            .map(|(opponent, you)| (Actions::from_index(opponent), Actions::from_index(you)))
+            .collect())
+    }
+
+    fn part_one(rounds: &Self::Input) -> anyhow::Result<u32> {
+        let mut action_total_score: u32 = 0;
+
+        for (opponent, you) in rounds {
+            action_total_score += you.get_value() + you.get_score(opponent);
+        }
+
+        Ok(action_total_score)
+    }
+
+    fn part_two(rounds: &Self::Input) -> anyhow::Result<u32> {
+        let mut outcome_total_score: u32 = 0;
+
+        for (opponent, you) in rounds {
+            let you = you.get_action(opponent);
+
+            outcome_total_score += you.get_value() + you.get_score(opponent);
+        }
+
+        Ok(outcome_total_score)
+    }
+}
@@ -0,0 +1,242 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context};
+
+use crate::{
+    day_2::game::{Game, Outcome},
+    parse::{self, ParseError},
+};
+
+#[derive(Clone, Debug)]
+pub struct Rules {
+    game: Game,
+    pub opponent: Vec<String>,
+    pub response: Vec<String>,
+    pub outcome: Vec<String>,
+    pub values: Vec<u32>,
+    pub outcome_scores: [u32; 3],
+}
+
+fn symbols(list: &str) -> Vec<String> {
+    list.split([' ', ','])
+        .filter(|symbol| !symbol.is_empty())
+        .map(|symbol| symbol.to_string())
+        .collect()
+}
+
+fn numbers(list: &str) -> anyhow::Result<Vec<u32>> {
+    symbols(list)
+        .iter()
+        .map(|number| {
+            number
+                .parse()
+                .with_context(|| format!("Invalid number {:?}", number))
+        })
+        .collect()
+}
+
+fn describe(symbols: &[String]) -> String {
+    match symbols {
+        [] => String::new(),
+        [symbol] => symbol.clone(),
+        [rest @ .., last] => format!("{} or {}", rest.join(", "), last),
+    }
+}
+
+fn check_unique(name: &str, symbols: &[String]) -> anyhow::Result<()> {
+    for (index, symbol) in symbols.iter().enumerate() {
+        if symbols[..index].contains(symbol) {
+            bail!("The {} symbol {:?} is used more than once", name, symbol);
+        }
+    }
+
+    Ok(())
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            game: Game::ROCK_PAPER_SCISSORS,
+            opponent: symbols("A B C"),
+            response: symbols("X Y Z"),
+            outcome: symbols("X Y Z"),
+            values: vec![1, 2, 3],
+            outcome_scores: [0, 3, 6],
+        }
+    }
+}
+
+impl Rules {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut rules = Rules::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                bail!("Expected `key = value` on line {} of the rules", index + 1);
+            };
+
+            rules
+                .set(key.trim(), value.trim())
+                .with_context(|| format!("On line {} of the rules", index + 1))?;
+        }
+
+        rules.validate()?;
+
+        Ok(rules)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("Failed to read rules {:?}", path))?;
+
+        Rules::parse(&text).with_context(|| format!("Invalid rules in {:?}", path))
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+        match key {
+            "opponent" => self.opponent = symbols(value),
+            "response" => self.response = symbols(value),
+            "outcome" => self.outcome = symbols(value),
+            "values" => self.values = numbers(value)?,
+            "outcomes" => match numbers(value)?[..] {
+                [lose, draw, win] => self.outcome_scores = [lose, draw, win],
+                _ => bail!("Expected three outcome scores, for a loss, draw and win"),
+            },
+            _ => bail!(
+                "Unknown rule {:?}, expected opponent, response, outcome, values or outcomes",
+                key
+            ),
+        }
+
+        Ok(())
+    }
+
+    pub fn validate(&mut self) -> anyhow::Result<()> {
+        let moves = self.opponent.len();
+        self.game = Game::new(moves)?;
+
+        if self.response.len() != moves {
+            bail!(
+                "Expected {} response symbols to match the opponent's, found {}",
+                moves,
+                self.response.len()
+            );
+        }
+
+        if self.values.len() != moves {
+            bail!(
+                "Expected {} move values, one per move, found {}",
+                moves,
+                self.values.len()
+            );
+        }
+
+        if self.outcome.len() != 3 {
+            bail!("Expected three outcome symbols, for a loss, draw and win");
+        }
+
+        check_unique("opponent", &self.opponent)?;
+        check_unique("response", &self.response)?;
+        check_unique("outcome", &self.outcome)?;
+
+        for symbol in &self.outcome {
+            if !self.response.contains(symbol) {
+                bail!(
+                    "The outcome symbol {:?} is not a response, expected one of {}",
+                    symbol,
+                    describe(&self.response)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn game(&self) -> Game {
+        self.game
+    }
+
+    pub fn parse_guide(&self, day: u8, input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        let mut rounds = vec![];
+
+        for line in parse::lines(day, input) {
+            let symbols: Vec<&str> = line.text.split_whitespace().collect();
+
+            let [opponent, you] = symbols[..] else {
+                return Err(line.error(
+                    1,
+                    format!(
+                        "Expected an opponent move and a response, like \"{} {}\"",
+                        self.opponent[0], self.response[0]
+                    ),
+                ));
+            };
+
+            let find =
+                |symbols: &[String], symbol: &str| symbols.iter().position(|other| other == symbol);
+
+            let opponent_index = find(&self.opponent, opponent).ok_or_else(|| {
+                line.error_at(
+                    opponent,
+                    format!("Expected an opponent move of {}", describe(&self.opponent)),
+                )
+            })?;
+
+            let you_index = find(&self.response, you).ok_or_else(|| {
+                line.error_at(
+                    you,
+                    format!("Expected a response of {}", describe(&self.response)),
+                )
+            })?;
+
+            rounds.push((opponent_index, you_index));
+        }
+
+        Ok(rounds)
+    }
+
+    pub fn get_score(&self, you: usize, opponent: usize) -> u32 {
+        let outcome = self.game.get_outcome(you, opponent);
+        self.values[you] + self.outcome_scores[outcome as usize]
+    }
+
+    pub fn get_outcome(&self, response: usize) -> Option<Outcome> {
+        let index = self
+            .outcome
+            .iter()
+            .position(|symbol| *symbol == self.response[response])?;
+
+        Some(Outcome::ALL[index])
+    }
+
+    pub fn action_total(&self, rounds: &[(usize, usize)]) -> u32 {
+        rounds
+            .iter()
+            .map(|(opponent, you)| self.get_score(*you, *opponent))
+            .sum()
+    }
+
+    pub fn outcome_total(&self, rounds: &[(usize, usize)]) -> anyhow::Result<u32> {
+        let mut total = 0;
+
+        for (opponent, response) in rounds {
+            let Some(outcome) = self.get_outcome(*response) else {
+                bail!(
+                    "The response {:?} has no outcome, expected one of {}",
+                    self.response[*response],
+                    describe(&self.outcome)
+                );
+            };
+
+            total += self.get_score(self.game.get_response(*opponent, outcome), *opponent);
+        }
+
+        Ok(total)
+    }
+}
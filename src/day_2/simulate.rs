@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+
+use crate::{
+    day_2::{
+        decrypt::Meaning,
+        game::{Game, Outcome},
+        rules::Rules,
+    },
+    generate::Rng,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Round {
+    pub number: usize,
+    pub opponent: usize,
+    pub you: usize,
+    pub outcome: Outcome,
+    pub points: u32,
+    pub running_score: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    Guide,
+    Random(u64),
+    Frequency,
+    Cycle(Vec<String>),
+}
+
+impl FromStr for Strategy {
+    type Err = anyhow::Error;
+
+    fn from_str(strategy: &str) -> anyhow::Result<Self> {
+        let (name, argument) = match strategy.split_once(':') {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (strategy, None),
+        };
+
+        match (name, argument) {
+            ("guide", None) => Ok(Strategy::Guide),
+            ("random", None) => Ok(Strategy::Random(0)),
+            ("random", Some(seed)) => Ok(Strategy::Random(seed.parse()?)),
+            ("frequency", None) => Ok(Strategy::Frequency),
+            ("cycle", Some(moves)) if !moves.is_empty() => {
+                let cycle = match moves.contains(',') {
+                    true => moves
+                        .split(',')
+                        .map(|name| name.trim().to_string())
+                        .collect(),
+                    false => moves.chars().map(|char| char.to_string()).collect(),
+                };
+
+                Ok(Strategy::Cycle(cycle))
+            }
+            _ => bail!(
+                "Unknown strategy {:?}, expected guide, random[:seed], frequency or cycle:<moves>",
+                strategy
+            ),
+        }
+    }
+}
+
+fn get_move(game: &Game, name: &str) -> anyhow::Result<usize> {
+    if let Ok(number) = name.parse::<usize>() {
+        if number == 0 || number > game.moves() {
+            bail!(
+                "Unknown move {} in cycle, expected 1 to {}",
+                number,
+                game.moves()
+            );
+        }
+
+        return Ok(number - 1);
+    }
+
+    let name = name.to_lowercase();
+    let names: Vec<String> = (0..game.moves())
+        .map(|index| game.get_name(index))
+        .collect();
+
+    if let Some(index) = names.iter().position(|other| other.to_lowercase() == name) {
+        return Ok(index);
+    }
+
+    let matches: Vec<usize> = (0..names.len())
+        .filter(|index| !name.is_empty() && names[*index].to_lowercase().starts_with(&name))
+        .collect();
+
+    match matches[..] {
+        [index] => Ok(index),
+        [] => bail!(
+            "Unknown move {:?} in cycle, expected one of {}",
+            name,
+            names.join(", ")
+        ),
+        _ => bail!(
+            "Ambiguous move {:?} in cycle, it could be {}",
+            name,
+            matches
+                .iter()
+                .map(|index| names[*index].as_str())
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ),
+    }
+}
+
+struct Opponent {
+    game: Game,
+    strategy: Strategy,
+    cycle: Vec<usize>,
+    rng: Rng,
+    seen: Vec<usize>,
+    round: usize,
+}
+
+impl Opponent {
+    fn new(game: Game, strategy: &Strategy) -> anyhow::Result<Self> {
+        let seed = match strategy {
+            Strategy::Random(seed) => *seed,
+            _ => 0,
+        };
+
+        let cycle = match strategy {
+            Strategy::Cycle(names) => names
+                .iter()
+                .map(|name| get_move(&game, name))
+                .collect::<anyhow::Result<Vec<usize>>>()?,
+            _ => vec![],
+        };
+
+        Ok(Opponent {
+            game,
+            strategy: strategy.clone(),
+            cycle,
+            rng: Rng::new(seed),
+            seen: vec![0; game.moves()],
+            round: 0,
+        })
+    }
+
+    fn next_move(&mut self, predicted: usize) -> usize {
+        let round = self.round;
+        self.round += 1;
+
+        match &self.strategy {
+            Strategy::Guide => predicted,
+            Strategy::Random(_) => self.rng.range(0, self.game.moves() - 1),
+            Strategy::Frequency => {
+                let favourite = (0..self.seen.len())
+                    .rev()
+                    .max_by_key(|index| self.seen[*index]);
+
+                self.game.get_response(favourite.unwrap_or(0), Outcome::Win)
+            }
+            Strategy::Cycle(_) => self.cycle[round % self.cycle.len()],
+        }
+    }
+
+    fn observe(&mut self, you: usize) {
+        self.seen[you] += 1;
+    }
+}
+
+pub fn play(
+    rules: &Rules,
+    rounds: &[(usize, usize)],
+    meaning: &Meaning,
+    strategy: &Strategy,
+) -> anyhow::Result<Vec<Round>> {
+    let game = rules.game();
+    let mut opponent = Opponent::new(game, strategy)?;
+    let mut running_score = 0;
+    let mut records = vec![];
+
+    for (index, (predicted, column)) in rounds.iter().enumerate() {
+        let you = meaning.get_action(rules, *column, *predicted);
+        let actual = opponent.next_move(*predicted);
+        opponent.observe(you);
+
+        let points = rules.get_score(you, actual);
+        running_score += points;
+
+        records.push(Round {
+            number: index + 1,
+            opponent: actual,
+            you,
+            outcome: game.get_outcome(you, actual),
+            points,
+            running_score,
+        });
+    }
+
+    Ok(records)
+}
+
+pub fn simulate(
+    rules: &Rules,
+    rounds: &[(usize, usize)],
+    meaning: &Meaning,
+) -> anyhow::Result<Vec<Round>> {
+    play(rules, rounds, meaning, &Strategy::Guide)
+}
+
+pub fn to_csv(rules: &Rules, games: &[(&str, Vec<Round>)]) -> String {
+    let game = rules.game();
+    let mut csv = "interpretation,round,opponent,you,outcome,points,running_score\n".to_string();
+
+    for (name, records) in games {
+        for round in records {
+            csv += &format!(
+                "{},{},{},{},{:?},{},{}\n",
+                name,
+                round.number,
+                game.get_name(round.opponent),
+                game.get_name(round.you),
+                round.outcome,
+                round.points,
+                round.running_score
+            );
+        }
+    }
+
+    csv
+}
@@ -0,0 +1,166 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context};
+
+use crate::day_3::{items::ItemSet, ALPHABET};
+
+pub const MAX_ITEMS: usize = 127;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alphabet {
+    items: Vec<char>,
+    priorities: Vec<usize>,
+    ascii: [u8; 128],
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        let mut alphabet = Alphabet {
+            items: vec![],
+            priorities: vec![],
+            ascii: [0; 128],
+        };
+
+        for (index, item) in ALPHABET.chars().enumerate() {
+            alphabet.set(item, index + 1).unwrap();
+        }
+
+        alphabet
+    }
+}
+
+impl Alphabet {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut alphabet = Alphabet::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((item, priority)) = line.split_once('=') else {
+                bail!(
+                    "Expected `item = priority` on line {} of the alphabet",
+                    index + 1
+                );
+            };
+
+            let mut chars = item.trim().chars();
+            let (Some(item), None) = (chars.next(), chars.next()) else {
+                bail!(
+                    "Expected a single character item on line {} of the alphabet, found {:?}",
+                    index + 1,
+                    item.trim()
+                );
+            };
+
+            let priority = priority.trim().parse().with_context(|| {
+                format!(
+                    "Invalid priority {:?} on line {} of the alphabet",
+                    priority.trim(),
+                    index + 1
+                )
+            })?;
+
+            alphabet
+                .set(item, priority)
+                .with_context(|| format!("On line {} of the alphabet", index + 1))?;
+        }
+
+        Ok(alphabet)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read alphabet {:?}", path))?;
+
+        Alphabet::parse(&text).with_context(|| format!("Invalid alphabet in {:?}", path))
+    }
+
+    pub fn set(&mut self, item: char, priority: usize) -> anyhow::Result<()> {
+        if priority == 0 {
+            bail!("The priority of {:?} must be at least 1", item);
+        }
+
+        if item.is_whitespace() || item.is_control() {
+            bail!("{:?} can't be used as an item", item);
+        }
+
+        if let Some(slot) = self.get_slot(item) {
+            self.priorities[slot - 1] = priority;
+            return Ok(());
+        }
+
+        if self.items.len() == MAX_ITEMS {
+            bail!("Alphabets are limited to {} items", MAX_ITEMS);
+        }
+
+        self.items.push(item);
+        self.priorities.push(priority);
+
+        if item.is_ascii() {
+            self.ascii[item as usize] = self.items.len() as u8;
+        }
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get_slot(&self, item: char) -> Option<usize> {
+        if item.is_ascii() {
+            return match self.ascii[item as usize] {
+                0 => None,
+                slot => Some(slot as usize),
+            };
+        }
+
+        self.items
+            .iter()
+            .position(|other| *other == item)
+            .map(|index| index + 1)
+    }
+
+    pub fn get_item(&self, slot: usize) -> Option<char> {
+        self.items.get(slot.checked_sub(1)?).copied()
+    }
+
+    pub fn get_priority(&self, item: char) -> Option<usize> {
+        self.get_slot(item).map(|slot| self.priorities[slot - 1])
+    }
+
+    pub fn item_set(&self, items: &str) -> ItemSet {
+        let mut set = ItemSet::EMPTY;
+
+        for item in items.chars() {
+            if let Some(slot) = self.get_slot(item) {
+                set.insert(slot);
+            }
+        }
+
+        set
+    }
+
+    pub fn items(&self, set: ItemSet) -> impl Iterator<Item = char> + '_ {
+        set.slots().filter_map(|slot| self.get_item(slot))
+    }
+
+    pub fn priority_sum(&self, set: ItemSet) -> usize {
+        set.slots().map(|slot| self.priorities[slot - 1]).sum()
+    }
+
+    pub fn describe(&self) -> String {
+        match self.items[ALPHABET.len()..].iter().collect::<String>() {
+            extra if extra.is_empty() => "a-z or A-Z".to_string(),
+            extra => format!("a-z, A-Z or one of {:?}", extra),
+        }
+    }
+}
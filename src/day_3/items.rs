@@ -0,0 +1,66 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ItemSet(u128);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, slot: usize) -> bool {
+        let added = self.0 & 1 << slot == 0;
+        self.0 |= 1 << slot;
+        added
+    }
+
+    pub fn contains(&self, slot: usize) -> bool {
+        slot < 128 && self.0 & 1 << slot != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn slots(&self) -> impl Iterator<Item = usize> {
+        let mut bits = self.0;
+
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+
+            let slot = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+            Some(slot)
+        })
+    }
+}
+
+impl FromIterator<usize> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(slots: I) -> Self {
+        let mut set = ItemSet::EMPTY;
+
+        for slot in slots {
+            set.insert(slot);
+        }
+
+        set
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ItemSet({:?})", self.slots().collect::<Vec<_>>())
+    }
+}
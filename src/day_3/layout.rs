@@ -0,0 +1,220 @@
+use anyhow::bail;
+
+use crate::{
+    day_3::{alphabet::Alphabet, items::ItemSet},
+    parse::{self, ParseError},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    compartments: usize,
+    group_size: usize,
+    alphabet: Alphabet,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout {
+            compartments: 2,
+            group_size: 3,
+            alphabet: Alphabet::default(),
+        }
+    }
+}
+
+impl Layout {
+    pub fn new(compartments: usize, group_size: usize) -> anyhow::Result<Self> {
+        if compartments < 2 {
+            bail!(
+                "Rucksacks need at least 2 compartments, got {}",
+                compartments
+            );
+        }
+
+        if group_size < 2 {
+            bail!("Groups need at least 2 elves, got {}", group_size);
+        }
+
+        Ok(Layout {
+            compartments,
+            group_size,
+            alphabet: Alphabet::default(),
+        })
+    }
+
+    pub fn with_alphabet(self, alphabet: Alphabet) -> Self {
+        Layout { alphabet, ..self }
+    }
+
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+
+    pub fn parse(&self, day: u8, input: &str) -> Result<Vec<String>, ParseError> {
+        let mut lines = vec![];
+
+        for line in parse::lines(day, input) {
+            let mut count = 0;
+
+            for item in line.text.chars() {
+                count += 1;
+
+                if self.alphabet.get_slot(item).is_none() {
+                    return Err(line.error(
+                        count,
+                        format!(
+                            "{:?} is not an item, expected {}",
+                            item,
+                            self.alphabet.describe()
+                        ),
+                    ));
+                }
+            }
+
+            if count % self.compartments != 0 {
+                return Err(line.error(
+                    count,
+                    format!(
+                        "A rucksack of {} items can't be split into {} equal compartments",
+                        count, self.compartments
+                    ),
+                ));
+            }
+
+            lines.push(line.text.to_string());
+        }
+
+        Ok(lines)
+    }
+
+    fn describe(&self, items: ItemSet) -> String {
+        self.alphabet.items(items).collect()
+    }
+
+    fn single_item(&self, items: ItemSet) -> char {
+        self.alphabet.items(items).next().unwrap()
+    }
+
+    pub fn compartments(&self) -> usize {
+        self.compartments
+    }
+
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    pub fn get_compartments<'a>(
+        &self,
+        number: usize,
+        line: &'a str,
+    ) -> anyhow::Result<impl Iterator<Item = &'a str> + 'a> {
+        let ascii = line.is_ascii();
+        let count = match ascii {
+            true => line.len(),
+            false => line.chars().count(),
+        };
+
+        if !count.is_multiple_of(self.compartments) {
+            bail!(
+                "Rucksack on line {} has {} items, which can't be split into {} equal compartments",
+                number,
+                count,
+                self.compartments
+            );
+        }
+
+        let size = count / self.compartments;
+        let offset = move |index: usize| match ascii {
+            true => index * size,
+            false => line
+                .char_indices()
+                .nth(index * size)
+                .map_or(line.len(), |(offset, _item)| offset),
+        };
+
+        Ok((0..self.compartments).map(move |index| &line[offset(index)..offset(index + 1)]))
+    }
+
+    pub fn get_misplaced(&self, number: usize, line: &str) -> anyhow::Result<char> {
+        let common = self
+            .get_compartments(number, line)?
+            .map(|compartment| self.alphabet.item_set(compartment))
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+
+        match common.len() {
+            1 => Ok(self.single_item(common)),
+            0 => bail!(
+                "Rucksack on line {} has no item common to all {} compartments",
+                number,
+                self.compartments
+            ),
+            count => bail!(
+                "Rucksack on line {} has {} items common to all {} compartments: {}",
+                number,
+                count,
+                self.compartments,
+                self.describe(common)
+            ),
+        }
+    }
+
+    pub fn get_badge(&self, first_number: usize, group: &[String]) -> anyhow::Result<char> {
+        let last_number = first_number + group.len() - 1;
+
+        if group.len() != self.group_size {
+            bail!(
+                "Group on lines {}-{} has {} rucksacks, expected {}",
+                first_number,
+                last_number,
+                group.len(),
+                self.group_size
+            );
+        }
+
+        let common = group
+            .iter()
+            .map(|line| self.alphabet.item_set(line))
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+
+        match common.len() {
+            1 => Ok(self.single_item(common)),
+            0 => bail!(
+                "Group on lines {}-{} has no badge common to all {} elves",
+                first_number,
+                last_number,
+                self.group_size
+            ),
+            count => bail!(
+                "Group on lines {}-{} has {} possible badges: {}",
+                first_number,
+                last_number,
+                count,
+                self.describe(common)
+            ),
+        }
+    }
+
+    pub fn error_sum(&self, lines: &[String]) -> anyhow::Result<usize> {
+        let mut error_sum = 0;
+
+        for (index, line) in lines.iter().enumerate() {
+            let item = self.get_misplaced(index + 1, line)?;
+            error_sum += self.alphabet.get_priority(item).unwrap();
+        }
+
+        Ok(error_sum)
+    }
+
+    pub fn badge_sum(&self, lines: &[String]) -> anyhow::Result<usize> {
+        let mut badge_sum = 0;
+
+        for (index, group) in lines.chunks(self.group_size).enumerate() {
+            let badge = self.get_badge(index * self.group_size + 1, group)?;
+            badge_sum += self.alphabet.get_priority(badge).unwrap();
+        }
+
+        Ok(badge_sum)
+    }
+}
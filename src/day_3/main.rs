@@ -1,60 +1,121 @@
-static INPUT: &str = include_str!("input.txt");
+use std::{env, path::PathBuf};
 
-const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+use advent_of_code::{
+    bench::{compare, speedup},
+    day_3::{alphabet::Alphabet, layout::Layout, naive, repack::plan, Day3},
+    generate::generate,
+    input::InputSource,
+    Solution,
+};
+use anyhow::{anyhow, bail, Context};
+
+const USAGE: &str = "Usage: day_3 [input|-] [--compartments <n>] [--group-size <n>]
+             [--alphabet <file>] [--plan]
+       day_3 --compare [--size <rucksacks>] [--runs <n>] [--seed <n>]";
+
+fn next_number<T: std::str::FromStr>(args: &mut impl Iterator<Item = String>) -> anyhow::Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(args.next().ok_or_else(|| anyhow!(USAGE))?.parse()?)
+}
+
+fn compare_part(
+    name: &str,
+    runs: usize,
+    naive: impl FnMut() -> anyhow::Result<usize>,
+    bitset: impl FnMut() -> anyhow::Result<usize>,
+) -> anyhow::Result<()> {
+    let [before, after] =
+        compare(runs, naive, bitset).with_context(|| format!("Comparing {}", name))?;
+
+    println!(
+        "{:<10}  {:>13.2?}  {:>13.2?}  {:>7.1}x",
+        name,
+        before.median,
+        after.median,
+        speedup(&before, &after)
+    );
+
+    Ok(())
+}
 
 fn main() -> anyhow::Result<()> {
-    let mut line_index = 0;
-    let mut common_chars: Vec<char> = vec![];
-    let mut error_sum = 0;
-    let mut group_sum = 0;
-
-    for line in INPUT.lines() {
-        let half_len = line.len() / 2;
-        let mut line_iter = line.chars();
-        let mut first_half: Vec<char> = vec![];
-
-        for _index in 0..half_len {
-            first_half.push(line_iter.next().unwrap());
-        }
+    let mut source = InputSource::default();
+    let mut compare = false;
+    let mut show_plan = false;
+    let mut alphabet_path = None;
+    let mut compartments = 2;
+    let mut group_size = 3;
+    let mut size = 300_000;
+    let mut runs = 10;
+    let mut seed = 0;
+    let mut args = env::args().skip(1);
 
-        for _index in 0..half_len {
-            let current_char = line_iter.next().unwrap();
-            if first_half.contains(&current_char) {
-                error_sum += ALPHABET.find(current_char).unwrap() + 1;
-                break;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--compare" => compare = true,
+            "--plan" => show_plan = true,
+            "--alphabet" => {
+                alphabet_path = Some(PathBuf::from(args.next().ok_or_else(|| anyhow!(USAGE))?))
             }
+            "--compartments" => compartments = next_number(&mut args)?,
+            "--group-size" => group_size = next_number(&mut args)?,
+            "--size" => size = next_number(&mut args)?,
+            "--runs" => runs = next_number(&mut args)?,
+            "--seed" => seed = next_number(&mut args)?,
+            flag if flag.starts_with("--") => bail!(USAGE),
+            _ => source = InputSource::from_arg(&arg),
         }
+    }
 
-        match line_index {
-            0 => {
-                common_chars = line.chars().collect::<Vec<char>>();
-            }
-            1 => {
-                let old_chars = common_chars.clone();
-                common_chars = vec![];
-                for current_char in line.chars() {
-                    if old_chars.contains(&current_char) {
-                        common_chars.push(current_char);
-                    }
-                }
-            }
-            2 => {
-                for current_char in line.chars() {
-                    if common_chars.contains(&current_char) {
-                        group_sum += ALPHABET.find(current_char).unwrap() + 1;
-                        break;
-                    }
-                }
+    let alphabet = match &alphabet_path {
+        Some(path) => Alphabet::load(path)?,
+        None => Alphabet::default(),
+    };
+    let layout = Layout::new(compartments, group_size)?.with_alphabet(alphabet);
+
+    if !compare {
+        let lines = layout.parse(Day3::DAY, &source.load(Day3::DAY)?)?;
+
+        if show_plan {
+            for line_plan in plan(&layout, &lines)? {
+                println!("{}", line_plan);
             }
-            _ => (),
+
+            return Ok(());
         }
 
-        line_index += 1;
-        line_index %= 3;
+        println!("{}: {}", Day3::PART_ONE, layout.error_sum(&lines)?);
+        println!("{}: {}", Day3::PART_TWO, layout.badge_sum(&lines)?);
+
+        return Ok(());
     }
 
-    println!("Sum of errors: {}", error_sum);
-    println!("Sum of common groups: {}", group_sum);
+    let lines = Day3::parse(&generate(Day3::DAY, size, seed)?)?;
+
+    println!(
+        "Comparing on {} generated rucksacks over {} runs",
+        lines.len(),
+        runs
+    );
+    println!(
+        "{:<10}  {:>13}  {:>13}  {:>8}",
+        "Part", "Vec::contains", "ItemSet", "Speedup"
+    );
+
+    compare_part(
+        "part_one",
+        runs,
+        || Ok(naive::error_sum(&lines)),
+        || Day3::part_one(&lines),
+    )?;
+    compare_part(
+        "part_two",
+        runs,
+        || Ok(naive::group_sum(&lines)),
+        || Day3::part_two(&lines),
+    )?;
 
     Ok(())
 }
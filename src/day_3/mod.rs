@@ -0,0 +1,33 @@
+pub mod alphabet;
+pub mod items;
+pub mod layout;
+pub mod naive;
+pub mod repack;
+
+use crate::{day_3::layout::Layout, Solution};
+
+pub struct Day3;
+
+pub const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const PART_ONE: &'static str = "Sum of errors";
+    const PART_TWO: &'static str = "Sum of common groups";
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Layout::default().parse(Self::DAY, input)?)
+    }
+
+    fn part_one(lines: &Self::Input) -> anyhow::Result<usize> {
+        Layout::default().error_sum(lines)
+    }
+
+    fn part_two(lines: &Self::Input) -> anyhow::Result<usize> {
+        Layout::default().badge_sum(lines)
+    }
+}
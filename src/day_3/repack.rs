@@ -0,0 +1,208 @@
+use std::{cmp::Reverse, fmt};
+
+use anyhow::bail;
+
+use crate::day_3::{
+    alphabet::{Alphabet, MAX_ITEMS},
+    items::ItemSet,
+    layout::Layout,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "move {} {} from compartment {} to {}",
+            self.count, self.item, self.from, self.to
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Swap {
+    In(char),
+    Out(char, usize),
+}
+
+impl fmt::Display for Swap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Swap::In(item) => write!(f, "swap in 1 {} as the badge", item),
+            Swap::Out(item, count) => write!(f, "swap out {} {}", count, item),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LinePlan {
+    pub number: usize,
+    pub moves: Vec<Move>,
+    pub swaps: Vec<Swap>,
+}
+
+impl fmt::Display for LinePlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let actions: Vec<String> = self
+            .moves
+            .iter()
+            .map(|action| action.to_string())
+            .chain(self.swaps.iter().map(|swap| swap.to_string()))
+            .collect();
+
+        match actions.is_empty() {
+            true => write!(f, "Line {}: nothing to do", self.number),
+            false => write!(f, "Line {}: {}", self.number, actions.join(", ")),
+        }
+    }
+}
+
+fn count_items(alphabet: &Alphabet, items: &str) -> [usize; MAX_ITEMS + 1] {
+    let mut counts = [0; MAX_ITEMS + 1];
+
+    for item in items.chars() {
+        if let Some(slot) = alphabet.get_slot(item) {
+            counts[slot] += 1;
+        }
+    }
+
+    counts
+}
+
+pub fn plan_rucksack(layout: &Layout, number: usize, line: &str) -> anyhow::Result<Vec<Move>> {
+    let alphabet = layout.alphabet();
+    let compartments: Vec<[usize; MAX_ITEMS + 1]> = layout
+        .get_compartments(number, line)?
+        .map(|compartment| count_items(alphabet, compartment))
+        .collect();
+    let mut moves = vec![];
+
+    for slot in alphabet.item_set(line).slots() {
+        let holders = compartments
+            .iter()
+            .filter(|counts| counts[slot] > 0)
+            .count();
+
+        if holders < 2 {
+            continue;
+        }
+
+        let keep = (0..compartments.len())
+            .rev()
+            .max_by_key(|index| compartments[*index][slot])
+            .unwrap();
+
+        for (index, counts) in compartments.iter().enumerate() {
+            if index != keep && counts[slot] > 0 {
+                moves.push(Move {
+                    item: alphabet.get_item(slot).unwrap(),
+                    count: counts[slot],
+                    from: index + 1,
+                    to: keep + 1,
+                });
+            }
+        }
+    }
+
+    Ok(moves)
+}
+
+pub fn plan_group(
+    layout: &Layout,
+    first_number: usize,
+    group: &[String],
+) -> anyhow::Result<Vec<Vec<Swap>>> {
+    if group.len() != layout.group_size() {
+        bail!(
+            "Group on lines {}-{} has {} rucksacks, expected {}",
+            first_number,
+            first_number + group.len() - 1,
+            group.len(),
+            layout.group_size()
+        );
+    }
+
+    let alphabet = layout.alphabet();
+    let elves: Vec<[usize; MAX_ITEMS + 1]> = group
+        .iter()
+        .map(|line| count_items(alphabet, line))
+        .collect();
+    let mut swaps = vec![vec![]; group.len()];
+
+    let common = group
+        .iter()
+        .map(|line| alphabet.item_set(line))
+        .reduce(ItemSet::intersection)
+        .unwrap_or_default();
+
+    if common.is_empty() {
+        let held_by = |slot: usize| elves.iter().filter(|counts| counts[slot] > 0).count();
+
+        let Some(badge) = (1..=alphabet.len())
+            .rev()
+            .filter(|slot| held_by(*slot) > 0)
+            .max_by_key(|slot| held_by(*slot))
+        else {
+            bail!(
+                "Group on lines {}-{} has no items to choose a badge from",
+                first_number,
+                first_number + group.len() - 1
+            );
+        };
+
+        for (counts, swaps) in elves.iter().zip(&mut swaps) {
+            if counts[badge] == 0 {
+                swaps.push(Swap::In(alphabet.get_item(badge).unwrap()));
+            }
+        }
+
+        return Ok(swaps);
+    }
+
+    let cost = |slot: usize| elves.iter().map(|counts| counts[slot]).min().unwrap();
+    let badge = common
+        .slots()
+        .max_by_key(|slot| (cost(*slot), Reverse(*slot)))
+        .unwrap();
+
+    for slot in common.slots().filter(|slot| *slot != badge) {
+        let elf = (0..elves.len())
+            .min_by_key(|elf| elves[*elf][slot])
+            .unwrap();
+
+        swaps[elf].push(Swap::Out(
+            alphabet.get_item(slot).unwrap(),
+            elves[elf][slot],
+        ));
+    }
+
+    Ok(swaps)
+}
+
+pub fn plan(layout: &Layout, lines: &[String]) -> anyhow::Result<Vec<LinePlan>> {
+    let mut plans = vec![];
+
+    for (index, group) in lines.chunks(layout.group_size()).enumerate() {
+        let first_number = index * layout.group_size() + 1;
+        let swaps = plan_group(layout, first_number, group)?;
+
+        for ((offset, line), swaps) in group.iter().enumerate().zip(swaps) {
+            let number = first_number + offset;
+
+            plans.push(LinePlan {
+                number,
+                moves: plan_rucksack(layout, number, line)?,
+                swaps,
+            });
+        }
+    }
+
+    Ok(plans)
+}
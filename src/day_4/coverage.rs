@@ -0,0 +1,163 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::interval::{Integer, Interval};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Segment<T> {
+    pub sections: Interval<T>,
+    pub depth: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Coverage<T> {
+    pub segments: Vec<Segment<T>>,
+}
+
+fn label<T: Integer>(sections: &Interval<T>) -> String {
+    match sections.length() {
+        1 => sections.start().to_string(),
+        _ => sections.to_string(),
+    }
+}
+
+fn describe<T: Integer>(sections: &[Interval<T>]) -> String {
+    let ranges: Vec<String> = sections.iter().map(label).collect();
+
+    match ranges.is_empty() {
+        true => "none".to_string(),
+        false => ranges.join(", "),
+    }
+}
+
+impl<T: Integer> Coverage<T> {
+    pub fn new(intervals: &[Interval<T>]) -> Self {
+        let mut changes: BTreeMap<T, isize> = BTreeMap::new();
+
+        for interval in intervals {
+            *changes.entry(interval.start()).or_default() += 1;
+
+            if let Some(after) = interval.end().successor() {
+                *changes.entry(after).or_default() -= 1;
+            }
+        }
+
+        let Some(last) = intervals.iter().map(|interval| interval.end()).max() else {
+            return Coverage { segments: vec![] };
+        };
+
+        let mut segments: Vec<Segment<T>> = vec![];
+        let mut depth = 0;
+        let mut start = None;
+
+        let mut push = |start: T, end: T, depth: usize| match segments.last_mut() {
+            Some(previous) if previous.depth == depth => {
+                previous.sections = previous.sections.hull(&Interval::point(end))
+            }
+            _ => segments.push(Segment {
+                sections: Interval::new(start, end).unwrap(),
+                depth,
+            }),
+        };
+
+        for (&position, &change) in &changes {
+            if position > last {
+                break;
+            }
+
+            if let Some(start) = start {
+                push(start, position.predecessor().unwrap(), depth);
+            }
+
+            depth = depth.checked_add_signed(change).unwrap();
+            start = Some(position);
+        }
+
+        if let Some(start) = start {
+            push(start, last, depth);
+        }
+
+        Coverage { segments }
+    }
+
+    pub fn get_sections(&self, depth: impl Fn(usize) -> bool) -> Vec<Interval<T>> {
+        let mut sections: Vec<Interval<T>> = vec![];
+
+        for segment in self.segments.iter().filter(|segment| depth(segment.depth)) {
+            match sections.last_mut() {
+                Some(previous) if previous.touches(&segment.sections) => {
+                    *previous = previous.hull(&segment.sections)
+                }
+                _ => sections.push(segment.sections),
+            }
+        }
+
+        sections
+    }
+
+    pub fn count_sections(&self, depth: impl Fn(usize) -> bool) -> u128 {
+        self.segments
+            .iter()
+            .filter(|segment| depth(segment.depth))
+            .map(|segment| segment.sections.length())
+            .sum()
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|segment| segment.depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn deepest(&self) -> Vec<Interval<T>> {
+        let max_depth = self.max_depth();
+        self.get_sections(|depth| depth == max_depth)
+    }
+}
+
+impl<T: Integer> Display for Coverage<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (Some(first), Some(last)) = (self.segments.first(), self.segments.last()) else {
+            return writeln!(f, "No sections are assigned");
+        };
+
+        writeln!(
+            f,
+            "Sections {}-{}: {} covered by nobody, {} by exactly one elf, {} by several",
+            first.sections.start(),
+            last.sections.end(),
+            self.count_sections(|depth| depth == 0),
+            self.count_sections(|depth| depth == 1),
+            self.count_sections(|depth| depth > 1)
+        )?;
+        writeln!(
+            f,
+            "Uncovered: {}",
+            describe(&self.get_sections(|depth| depth == 0))
+        )?;
+        writeln!(
+            f,
+            "Maximum overlap: {} elves on {}",
+            self.max_depth(),
+            describe(&self.deepest())
+        )?;
+        writeln!(f, "\nSections  Elves")?;
+
+        for segment in &self.segments {
+            let row = format!(
+                "{:>8}  {:>5}  {}",
+                label(&segment.sections),
+                segment.depth,
+                "#".repeat(segment.depth.min(60))
+            );
+
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
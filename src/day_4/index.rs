@@ -0,0 +1,129 @@
+use crate::interval::{Integer, Interval};
+
+const LEAF_SIZE: usize = 32;
+
+#[derive(Clone, Debug)]
+pub struct IntervalIndex<T> {
+    intervals: Vec<Interval<T>>,
+    order: Vec<usize>,
+    sorted: Vec<Interval<T>>,
+    subtree_max: Vec<T>,
+    maxes: Vec<T>,
+}
+
+impl<T: Integer> IntervalIndex<T> {
+    pub fn new(intervals: &[Interval<T>]) -> Self {
+        let mut order: Vec<usize> = (0..intervals.len()).collect();
+        order.sort_by_key(|index| (intervals[*index].start(), intervals[*index].end()));
+
+        let mut maxes: Vec<T> = intervals.iter().map(|interval| interval.end()).collect();
+        maxes.sort();
+
+        let mut index = IntervalIndex {
+            intervals: intervals.to_vec(),
+            sorted: order.iter().map(|index| intervals[*index]).collect(),
+            subtree_max: order.iter().map(|index| intervals[*index].end()).collect(),
+            order,
+            maxes,
+        };
+
+        index.build(0, intervals.len());
+        index
+    }
+
+    fn build(&mut self, low: usize, high: usize) -> Option<T> {
+        if low >= high {
+            return None;
+        }
+
+        let middle = (low + high) / 2;
+        let mut max = self.subtree_max[middle];
+
+        for child in [self.build(low, middle), self.build(middle + 1, high)] {
+            max = max.max(child.unwrap_or(max));
+        }
+
+        self.subtree_max[middle] = max;
+        Some(max)
+    }
+
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Interval<T>> {
+        self.intervals.get(index).copied()
+    }
+
+    fn search(&self, low: usize, high: usize, query: &Interval<T>, found: &mut Vec<usize>) {
+        if low >= high {
+            return;
+        }
+
+        let middle = (low + high) / 2;
+
+        if self.subtree_max[middle] < query.start() {
+            return;
+        }
+
+        if high - low <= LEAF_SIZE {
+            for position in low..high {
+                let interval = self.sorted[position];
+
+                if interval.start() > query.end() {
+                    return;
+                }
+
+                if interval.end() >= query.start() {
+                    found.push(self.order[position]);
+                }
+            }
+
+            return;
+        }
+
+        self.search(low, middle, query, found);
+
+        if self.sorted[middle].start() > query.end() {
+            return;
+        }
+
+        if self.sorted[middle].end() >= query.start() {
+            found.push(self.order[middle]);
+        }
+
+        self.search(middle + 1, high, query, found);
+    }
+
+    pub fn overlapping(&self, query: &Interval<T>) -> Vec<usize> {
+        let mut found = vec![];
+
+        self.search(0, self.len(), query, &mut found);
+        found
+    }
+
+    pub fn count_overlapping(&self, query: &Interval<T>) -> usize {
+        let starting_after = self.len()
+            - self
+                .sorted
+                .partition_point(|interval| interval.start() <= query.end());
+        let ending_before = self.maxes.partition_point(|max| *max < query.start());
+
+        self.len() - starting_after - ending_before
+    }
+
+    pub fn count_overlapping_pairs(&self) -> u64 {
+        let count = self.len() as u64;
+        let mut disjoint = 0;
+
+        for interval in &self.sorted {
+            disjoint += self.maxes.partition_point(|max| *max < interval.start()) as u64;
+        }
+
+        count * count.saturating_sub(1) / 2 - disjoint
+    }
+}
@@ -1,43 +1,215 @@
-static INPUT: &str = include_str!("input.txt");
+use std::env;
+
+use advent_of_code::{
+    bench::{compare, speedup, Stats},
+    day_4::{coverage::Coverage, index::IntervalIndex, Day4},
+    generate::{generate, Rng},
+    input::InputSource,
+    interval::Interval,
+    Solution,
+};
+use anyhow::{anyhow, bail, Context};
+
+const USAGE: &str = "Usage: day_4 [input|-] [--coverage] [--overlapping <min-max>] [--pairs]
+       day_4 --compare [--size <pairs>] [--runs <n>] [--seed <n>]";
+
+fn next_value(args: &mut impl Iterator<Item = String>) -> anyhow::Result<String> {
+    args.next().ok_or_else(|| anyhow!(USAGE))
+}
+
+fn parse_query(range: &str) -> anyhow::Result<Interval<u8>> {
+    let (min, max) = range
+        .split_once('-')
+        .ok_or_else(|| anyhow!("Expected a section range like 2-4, found {:?}", range))?;
+
+    Interval::new(min.parse()?, max.parse()?)
+        .ok_or_else(|| anyhow!("The range {} ends before it starts", range))
+}
+
+fn assignments(pairs: &[(Interval<u8>, Interval<u8>)]) -> Vec<Interval<u8>> {
+    pairs
+        .iter()
+        .flat_map(|(first, second)| [*first, *second])
+        .collect()
+}
 
 fn main() -> anyhow::Result<()> {
-    let mut containing_count: u32 = 0;
-    let mut overlapping_count: u32 = 0;
-
-    for line in INPUT.lines() {
-        let line: Vec<&str> = line.trim().split(",").collect();
-        let first: Vec<&str> = line[0].split("-").collect();
-        let second: Vec<&str> = line[1].split("-").collect();
-
-        let first_min: u8 = first[0].parse()?;
-        let first_max: u8 = first[1].parse()?;
-        let first_range = first_max - first_min;
-
-        let second_min: u8 = second[0].parse()?;
-        let second_max: u8 = second[1].parse()?;
-        let second_range = second_max - second_min;
-
-        if (first_range < second_range && first_min >= second_min && first_max <= second_max)
-            || (second_min >= first_min && second_max <= first_max)
-        {
-            containing_count += 1;
+    let mut source = InputSource::default();
+    let mut coverage = false;
+    let mut query = None;
+    let mut pairs = false;
+    let mut compare_index = false;
+    let mut size = 5_000;
+    let mut runs = 10;
+    let mut seed = 0;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--coverage" => coverage = true,
+            "--overlapping" => query = Some(parse_query(&next_value(&mut args)?)?),
+            "--pairs" => pairs = true,
+            "--compare" => compare_index = true,
+            "--size" => size = next_value(&mut args)?.parse()?,
+            "--runs" => runs = next_value(&mut args)?.parse()?,
+            "--seed" => seed = next_value(&mut args)?.parse()?,
+            flag if flag.starts_with("--") => bail!(USAGE),
+            _ => source = InputSource::from_arg(&arg),
+        }
+    }
+
+    if compare_index {
+        return run_compare(size, runs, seed);
+    }
+
+    let input = source.load(Day4::DAY)?;
+    let input = Day4::parse(&input)?;
+
+    println!("{}: {}", Day4::PART_ONE, Day4::part_one(&input)?);
+    println!("{}: {}", Day4::PART_TWO, Day4::part_two(&input)?);
+
+    let assignments = assignments(&input);
+
+    if coverage {
+        print!("\n{}", Coverage::new(&assignments));
+    }
+
+    if query.is_some() || pairs {
+        let index = IntervalIndex::new(&assignments);
+
+        if let Some(query) = query {
+            let found = index.overlapping(&query);
+
+            println!("\n{} assignments overlap {}:", found.len(), query);
+
+            for assignment in found {
+                println!(
+                    "  line {:>5}, {} elf: {}",
+                    assignment / 2 + 1,
+                    ["first", "second"][assignment % 2],
+                    assignments[assignment]
+                );
+            }
         }
 
-        if first_min >= second_min && first_min <= second_max
-            || first_max <= second_max && first_max >= second_min
-            || second_min >= first_min && second_min <= first_max
-            || second_max <= first_max && second_max >= first_min
-        {
-            overlapping_count += 1;
+        if pairs {
+            println!(
+                "\nOverlapping pairs across all {} assignments: {}",
+                index.len(),
+                index.count_overlapping_pairs()
+            );
         }
     }
 
-    println!("Containing count: {}", containing_count);
+    Ok(())
+}
+
+fn run_compare(size: usize, runs: usize, seed: u64) -> anyhow::Result<()> {
+    let assignments = assignments(&Day4::parse(&generate(Day4::DAY, size, seed)?)?);
+    let index = IntervalIndex::new(&assignments);
+
+    let mut by_start: Vec<usize> = (0..assignments.len()).collect();
+    by_start.sort_by_key(|index| (assignments[*index].start(), assignments[*index].end()));
+
+    let mut rng = Rng::new(seed);
+    let queries: Vec<Interval<u8>> = (0..1000)
+        .map(|_query| {
+            let min = rng.range(1, 99);
+            let max = rng.range(min, (min + 4).min(99));
+            Interval::new(min as u8, max as u8).unwrap()
+        })
+        .collect();
 
+    let matches: usize = queries
+        .iter()
+        .map(|query| index.count_overlapping(query))
+        .sum();
+
+    println!(
+        "Comparing on {} generated assignments over {} runs, queries match {} on average",
+        assignments.len(),
+        runs,
+        matches / queries.len()
+    );
     println!(
-        "Overlapping count: {}",
-        overlapping_count
+        "{:<24}  {:>12}  {:>12}  {:>8}",
+        "Operation", "Pairwise", "Index", "Speedup"
     );
 
+    let [before, after] = compare(
+        runs,
+        || {
+            let mut count = 0u64;
+
+            for (position, first) in assignments.iter().enumerate() {
+                for second in &assignments[position + 1..] {
+                    count += first.overlaps(second) as u64;
+                }
+            }
+
+            Ok(count)
+        },
+        || Ok(IntervalIndex::new(&assignments).count_overlapping_pairs()),
+    )
+    .context("On counting overlapping pairs")?;
+    print_row("count overlapping pairs", &before, &after);
+
+    let [before, after] = compare(
+        runs,
+        || {
+            Ok(queries
+                .iter()
+                .map(|query| {
+                    by_start
+                        .iter()
+                        .copied()
+                        .filter(|index| assignments[*index].overlaps(query))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>())
+        },
+        || {
+            Ok(queries
+                .iter()
+                .map(|query| index.overlapping(query))
+                .collect::<Vec<_>>())
+        },
+    )
+    .context("On finding overlapping assignments")?;
+    print_row("1000 overlap queries", &before, &after);
+
+    let [before, after] = compare(
+        runs,
+        || {
+            Ok(queries
+                .iter()
+                .map(|query| {
+                    assignments
+                        .iter()
+                        .filter(|other| other.overlaps(query))
+                        .count()
+                })
+                .collect::<Vec<_>>())
+        },
+        || {
+            Ok(queries
+                .iter()
+                .map(|query| index.count_overlapping(query))
+                .collect::<Vec<_>>())
+        },
+    )
+    .context("On counting overlapping assignments")?;
+    print_row("1000 overlap counts", &before, &after);
+
     Ok(())
 }
+
+fn print_row(name: &str, before: &Stats, after: &Stats) {
+    println!(
+        "{:<24}  {:>12.2?}  {:>12.2?}  {:>7.1}x",
+        name,
+        before.median,
+        after.median,
+        speedup(before, after)
+    );
+}
@@ -0,0 +1,73 @@
+pub mod coverage;
+pub mod index;
+
+use crate::{
+    interval::Interval,
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+pub struct Day4;
+
+fn parse_range(line: &Line, range: &str) -> Result<Interval<u8>, ParseError> {
+    let (min, max) = range
+        .split_once('-')
+        .ok_or_else(|| line.error_at(range, "Expected a section range like 2-4"))?;
+
+    Interval::new(line.parse(min)?, line.parse(max)?).ok_or_else(|| {
+        line.error_at(
+            range,
+            format!("The range {} ends before it starts", range.trim()),
+        )
+    })
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const PART_ONE: &'static str = "Containing count";
+    const PART_TWO: &'static str = "Overlapping count";
+
+    type Input = Vec<(Interval<u8>, Interval<u8>)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut pairs = vec![];
+
+        for line in parse::lines(Self::DAY, input) {
+            let (first, second) = line
+                .text
+                .trim()
+                .split_once(',')
+                .ok_or_else(|| line.error(1, "Expected a pair of ranges like 2-4,6-8"))?;
+
+            pairs.push((parse_range(&line, first)?, parse_range(&line, second)?));
+        }
+
+        Ok(pairs)
+    }
+
+    fn part_one(pairs: &Self::Input) -> anyhow::Result<u32> {
+        let mut containing_count: u32 = 0;
+
+        for (first, second) in pairs {
+            if first.contains(second) || second.contains(first) {
+                containing_count += 1;
+            }
+        }
+
+        Ok(containing_count)
+    }
+
+    fn part_two(pairs: &Self::Input) -> anyhow::Result<u32> {
+        let mut overlapping_count: u32 = 0;
+
+        for (first, second) in pairs {
+            if first.overlaps(second) {
+                overlapping_count += 1;
+            }
+        }
+
+        Ok(overlapping_count)
+    }
+}
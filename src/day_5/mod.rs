@@ -0,0 +1,172 @@
+use crate::{
+    parse::{self, Line, ParseError},
+    Solution,
+};
+
+pub struct Day5;
+
+pub struct Move {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub struct Cargo {
+    pub crate_stacks: Vec<Vec<char>>,
+    pub moves: Vec<Move>,
+}
+
+fn get_stack_tops(crate_stacks: &[Vec<char>]) -> String {
+    let mut stack_tops = String::new();
+
+    for stack in crate_stacks {
+        if let Some(char) = stack.last() {
+            stack_tops.push(*char);
+        }
+    }
+
+    stack_tops
+}
+
+fn parse_move(line: &Line, stack_count: usize) -> Result<Move, ParseError> {
+    let fields: Vec<&str> = line.text.split_whitespace().collect();
+
+    let ["move", amount, "from", from, "to", to] = fields[..] else {
+        return Err(line.error(1, "Expected a move like \"move 1 from 2 to 1\""));
+    };
+
+    let amount = line.parse(amount)?;
+
+    let stack = |field| -> Result<usize, ParseError> {
+        let stack = line.parse(field)?;
+
+        if stack == 0 || stack > stack_count {
+            return Err(line.error_at(
+                field,
+                format!(
+                    "Stack {} does not exist, expected 1 to {}",
+                    stack, stack_count
+                ),
+            ));
+        }
+
+        Ok(stack)
+    };
+
+    Ok(Move {
+        amount,
+        from: stack(from)?,
+        to: stack(to)?,
+    })
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const PART_ONE: &'static str = "Stack tops 9000";
+    const PART_TWO: &'static str = "Stack tops 9001";
+
+    type Input = Cargo;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut lines = parse::lines(Self::DAY, input);
+        let mut drawing: Vec<Line> = vec![];
+
+        let stack_count = loop {
+            let Some(line) = lines.next() else {
+                let message = "Missing the stack numbers line below the crates";
+
+                return Err(match drawing.last() {
+                    Some(line) => line.error(1, message),
+                    None => ParseError {
+                        day: Self::DAY,
+                        line: 1,
+                        column: 1,
+                        text: String::new(),
+                        message: message.to_string(),
+                    },
+                }
+                .into());
+            };
+
+            if line.text.starts_with(" 1") {
+                break line.text.split_whitespace().count();
+            }
+
+            drawing.push(line);
+        };
+
+        let mut crate_stacks: Vec<Vec<char>> = vec![vec![]; stack_count];
+
+        for line in drawing.iter().rev() {
+            let bytes = line.text.as_bytes();
+
+            for (index, stack) in crate_stacks.iter_mut().enumerate() {
+                match (
+                    bytes.get(index * 4),
+                    bytes.get(index * 4 + 1),
+                    bytes.get(index * 4 + 2),
+                ) {
+                    (Some(b'['), Some(char), Some(b']')) if char.is_ascii_graphic() => {
+                        stack.push(*char as char)
+                    }
+                    (None | Some(b' '), None | Some(b' '), None | Some(b' ')) => (),
+                    _ => {
+                        return Err(line
+                            .error(index * 4 + 1, "Expected a crate like [A] or an empty space")
+                            .into())
+                    }
+                }
+            }
+        }
+
+        let mut moves = vec![];
+
+        for line in lines {
+            if line.text.trim().is_empty() {
+                continue;
+            }
+
+            moves.push(parse_move(&line, stack_count)?);
+        }
+
+        Ok(Cargo {
+            crate_stacks,
+            moves,
+        })
+    }
+
+    fn part_one(cargo: &Self::Input) -> anyhow::Result<String> {
+        let mut crate_stacks = cargo.crate_stacks.clone();
+
+        for current_move in &cargo.moves {
+            for _index in 0..current_move.amount {
+                if let Some(char) = crate_stacks[current_move.from - 1].pop() {
+                    crate_stacks[current_move.to - 1].push(char);
+                }
+            }
+        }
+
+        Ok(get_stack_tops(&crate_stacks))
+    }
+
+    fn part_two(cargo: &Self::Input) -> anyhow::Result<String> {
+        let mut crate_stacks_9001 = cargo.crate_stacks.clone();
+
+        for current_move in &cargo.moves {
+            let mut buffer_9001: Vec<char> = vec![];
+
+            for _index in 0..current_move.amount {
+                if let Some(char) = crate_stacks_9001[current_move.from - 1].pop() {
+                    buffer_9001.push(char);
+                }
+            }
+
+            buffer_9001.reverse();
+            crate_stacks_9001[current_move.to - 1].append(&mut buffer_9001);
+        }
+
+        Ok(get_stack_tops(&crate_stacks_9001))
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::anyhow;
+
+use crate::Solution;
+
+pub struct Day6;
+
+pub fn is_completely_different(char_vec: &[char]) -> bool {
+    for index_1 in 0..char_vec.len() {
+        for index_2 in index_1 + 1..char_vec.len() {
+            if char_vec[index_1] == char_vec[index_2] {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+pub fn find_marker(chars: &[char], size: usize) -> Option<usize> {
+    chars
+        .windows(size)
+        .position(is_completely_different)
+        .map(|index| index + size)
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const PART_ONE: &'static str = "Start-of-packet index";
+    const PART_TWO: &'static str = "Start-of-message index";
+
+    type Input = Vec<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.trim().chars().collect())
+    }
+
+    fn part_one(chars: &Self::Input) -> anyhow::Result<usize> {
+        find_marker(chars, 4).ok_or_else(|| anyhow!("No start-of-packet marker found"))
+    }
+
+    fn part_two(chars: &Self::Input) -> anyhow::Result<usize> {
+        find_marker(chars, 14).ok_or_else(|| anyhow!("No start-of-message marker found"))
+    }
+}
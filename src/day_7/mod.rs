@@ -0,0 +1,111 @@
+pub mod util;
+
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::anyhow;
+
+use crate::{day_7::util::*, parse, Solution};
+
+pub struct Day7;
+
+fn count_sizes(dir: Rc<RefCell<Directory>>) -> usize {
+    let dir = dir.borrow();
+    let size = dir.get_size();
+    let mut total_size = if size < 100_000 { size } else { 0 };
+
+    for subdir in dir.get_subdirectories() {
+        total_size += count_sizes(subdir);
+    }
+
+    total_size
+}
+
+fn compile_directory_sizes(vector: &mut Vec<usize>, dir: Rc<RefCell<Directory>>) {
+    let dir = dir.borrow();
+    vector.push(dir.get_size());
+
+    for subdir in dir.get_subdirectories() {
+        compile_directory_sizes(vector, subdir)
+    }
+}
+
+fn change_directory(
+    directory: &str,
+    current_dir: &Rc<RefCell<Directory>>,
+) -> Option<Rc<RefCell<Directory>>> {
+    if directory == ".." {
+        current_dir.borrow().get_parent()
+    } else {
+        match current_dir.borrow_mut().get_child(directory)? {
+            Item::Directory(directory) => Some(directory),
+            Item::File(_file) => None,
+        }
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const PART_ONE: &'static str = "Total less than 100,000";
+    const PART_TWO: &'static str = "Size of deleted directory";
+
+    type Input = Rc<RefCell<Directory>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let root = Directory::new_root();
+        let mut current_dir = root.clone();
+
+        for line in parse::lines(Self::DAY, input) {
+            let fields: Vec<&str> = line.text.split_whitespace().collect();
+
+            match fields[..] {
+                ["$", "cd", "/"] => current_dir = root.clone(),
+                ["$", "ls"] => (),
+                ["$", "cd", directory] => {
+                    current_dir = change_directory(directory, &current_dir).ok_or_else(|| {
+                        line.error_at(directory, format!("No directory named {:?}", directory))
+                    })?
+                }
+                ["$", ..] => return Err(line.error(1, "Expected a cd or ls command").into()),
+                ["dir", name] => current_dir.borrow_mut().add_directory(name),
+                [size, name] => current_dir.borrow_mut().add_file(name, line.parse(size)?),
+                _ => {
+                    return Err(line
+                        .error(1, "Expected a command, a directory or a file listing")
+                        .into())
+                }
+            }
+        }
+
+        Ok(root)
+    }
+
+    fn part_one(root: &Self::Input) -> anyhow::Result<usize> {
+        Ok(count_sizes(root.clone()))
+    }
+
+    fn part_two(root: &Self::Input) -> anyhow::Result<usize> {
+        let used_space = root.borrow().get_size();
+        let free_space = 70_000_000_usize.checked_sub(used_space).ok_or_else(|| {
+            anyhow!(
+                "The filesystem uses {} bytes, more than the disk's 70000000",
+                used_space
+            )
+        })?;
+        let needed_space = 30_000_000_usize.saturating_sub(free_space);
+
+        if needed_space == 0 {
+            return Ok(0);
+        }
+
+        let mut sizes = vec![];
+        compile_directory_sizes(&mut sizes, root.clone());
+        sizes.sort();
+
+        sizes
+            .into_iter()
+            .find(|size| *size >= needed_space)
+            .ok_or_else(|| anyhow!("No directory is large enough to delete"))
+    }
+}
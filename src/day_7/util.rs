@@ -50,7 +50,7 @@ impl Directory {
         self.children.push(Item::File(Rc::new(RefCell::new(
             File {
                 name: name.to_string(),
-                size: size,
+                size,
                 parent: self.this.upgrade().unwrap(),
             },
         ))))
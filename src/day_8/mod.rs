@@ -0,0 +1,128 @@
+pub mod viewer;
+
+use crate::{day_8::viewer::Viewer, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const PART_ONE: &'static str = "Total visible trees";
+    const PART_TWO: &'static str = "Highest possible scenic score";
+
+    type Input = Viewer;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Viewer::new(Self::DAY, input)?)
+    }
+
+    fn part_one(viewer: &Self::Input) -> anyhow::Result<u32> {
+        let mut viewer = viewer.clone();
+        viewer.reset();
+
+        for y in 0..viewer.height() {
+            let mut current_num = viewer.get_height(0, y);
+            viewer.set_visible(0, y);
+
+            for x in 1..viewer.width() {
+                let next_num = viewer.get_height(x, y);
+                if next_num > current_num {
+                    current_num = next_num;
+                    viewer.set_visible(x, y);
+                }
+            }
+
+            let mut current_num = viewer.get_height(viewer.width() - 1, y);
+            viewer.set_visible(viewer.width() - 1, y);
+
+            for x in (0..viewer.width() - 1).rev() {
+                let next_num = viewer.get_height(x, y);
+                if next_num > current_num {
+                    current_num = next_num;
+                    viewer.set_visible(x, y);
+                }
+            }
+        }
+
+        for x in 0..viewer.width() {
+            let mut current_num = viewer.get_height(x, 0);
+            viewer.set_visible(x, 0);
+
+            for y in 1..viewer.height() {
+                let next_num = viewer.get_height(x, y);
+                if next_num > current_num {
+                    current_num = next_num;
+                    viewer.set_visible(x, y);
+                }
+            }
+
+            let mut current_num = viewer.get_height(x, viewer.height() - 1);
+            viewer.set_visible(x, viewer.height() - 1);
+
+            for y in (0..viewer.height() - 1).rev() {
+                let next_num = viewer.get_height(x, y);
+                if next_num > current_num {
+                    current_num = next_num;
+                    viewer.set_visible(x, y);
+                }
+            }
+        }
+
+        Ok(viewer.count_visible())
+    }
+
+    fn part_two(viewer: &Self::Input) -> anyhow::Result<usize> {
+        let mut scenic_score: usize = 0;
+
+        for x in 0..viewer.width() {
+            for y in 0..viewer.height() {
+                let node = viewer.get_height(x, y);
+
+                let mut east = 0;
+                for nx in x + 1..viewer.width() {
+                    let next_node = viewer.get_height(nx, y);
+                    east += 1;
+                    if next_node >= node {
+                        break;
+                    }
+                }
+
+                let mut west = 0;
+                for nx in (0..x).rev() {
+                    let next_node = viewer.get_height(nx, y);
+                    west += 1;
+                    if next_node >= node {
+                        break;
+                    }
+                }
+
+                let mut north = 0;
+                for ny in y + 1..viewer.height() {
+                    let next_node = viewer.get_height(x, ny);
+                    north += 1;
+                    if next_node >= node {
+                        break;
+                    }
+                }
+
+                let mut south = 0;
+                for ny in (0..y).rev() {
+                    let next_node = viewer.get_height(x, ny);
+                    south += 1;
+                    if next_node >= node {
+                        break;
+                    }
+                }
+
+                let new_scenic_score = north * east * south * west;
+
+                if new_scenic_score > scenic_score {
+                    scenic_score = new_scenic_score;
+                }
+            }
+        }
+
+        Ok(scenic_score)
+    }
+}
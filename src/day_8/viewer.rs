@@ -1,37 +1,72 @@
+use crate::parse::{self, ParseError};
+
+#[derive(Clone)]
 pub struct Viewer {
     height_map: Vec<Vec<u8>>,
     visible_grid: Vec<Vec<bool>>,
 }
 
 impl Viewer {
-    pub fn new(input: &str) -> Self {
+    pub fn new(day: u8, input: &str) -> Result<Self, ParseError> {
         let mut height_map: Vec<Vec<u8>> = vec![];
         let mut visible_grid: Vec<Vec<bool>> = vec![];
 
-        for line in input.lines() {
+        for line in parse::lines(day, input) {
             let mut current_heights = vec![];
             let mut current_visible = vec![];
 
-            for char in line.trim().chars() {
+            let row = line.text.trim();
+            let start = line.column_of(row);
+
+            for (column, char) in row.chars().enumerate() {
+                let height = char.to_digit(10).ok_or_else(|| {
+                    line.error(start + column, format!("{:?} is not a tree height", char))
+                })?;
+
                 current_visible.push(false);
-                current_heights.push(char.to_digit(10).unwrap() as u8);
+                current_heights.push(height as u8);
+            }
+
+            if current_heights.is_empty() {
+                return Err(line.error(1, "Expected a row of tree heights"));
+            }
+
+            if let Some(first_row) = height_map.first() {
+                if current_heights.len() != first_row.len() {
+                    return Err(line.error(
+                        1,
+                        format!(
+                            "Expected {} trees in this row, found {}",
+                            first_row.len(),
+                            current_heights.len()
+                        ),
+                    ));
+                }
             }
 
             height_map.push(current_heights);
             visible_grid.push(current_visible);
         }
 
-        Viewer {
-            height_map: height_map,
-            visible_grid: visible_grid,
+        if height_map.is_empty() {
+            return Err(ParseError {
+                day,
+                line: 1,
+                column: 1,
+                text: String::new(),
+                message: "Expected a grid of tree heights".to_string(),
+            });
         }
+
+        Ok(Viewer {
+            height_map,
+            visible_grid,
+        })
     }
 
     pub fn reset(&mut self) {
-        for y in 0..self.height() {
-            for x in 0..self.width() {
-                self.visible_grid[y][x] = false;
-            }
+        for row in &mut self.visible_grid {
+            row.fill(false);
         }
     }
 
@@ -0,0 +1,370 @@
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+use anyhow::bail;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    pub fn range(&mut self, min: usize, max: usize) -> usize {
+        min + (self.next_u64() % (max - min + 1) as u64) as usize
+    }
+
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.range(1, denominator) <= numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0, index));
+        }
+    }
+}
+
+pub fn default_size(day: u8) -> usize {
+    match day {
+        1 => 250,
+        2 => 2500,
+        3 => 300,
+        4 => 1000,
+        5 => 500,
+        6 => 4096,
+        7 => 180,
+        8 => 99,
+        _ => 0,
+    }
+}
+
+pub fn scaled_size(day: u8, scale: usize) -> usize {
+    match day {
+        8 => (default_size(day) as f64 * (scale as f64).sqrt()).round() as usize,
+        _ => default_size(day) * scale,
+    }
+}
+
+pub fn generate(day: u8, size: usize, seed: u64) -> anyhow::Result<String> {
+    let mut rng = Rng::new(seed);
+
+    Ok(match day {
+        1 => calories(&mut rng, size),
+        2 => strategy_guide(&mut rng, size),
+        3 => rucksacks(&mut rng, size),
+        4 => section_pairs(&mut rng, size),
+        5 => crate_moves(&mut rng, size),
+        6 => datastream(&mut rng, size),
+        7 => terminal(&mut rng, size),
+        8 => tree_grid(&mut rng, size),
+        _ => bail!("Day {} has no input generator", day),
+    })
+}
+
+pub fn generate_to(day: u8, size: usize, seed: u64, writer: &mut impl Write) -> anyhow::Result<()> {
+    match day {
+        1 => write_calories(&mut Rng::new(seed), size, writer)?,
+        _ => writer.write_all(generate(day, size, seed)?.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+pub fn write_calories(rng: &mut Rng, elves: usize, writer: &mut impl Write) -> io::Result<()> {
+    for elf in 0..elves.max(1) {
+        if elf > 0 {
+            writeln!(writer)?;
+        }
+
+        for _item in 0..rng.range(1, 15) {
+            writeln!(writer, "{}", rng.range(1000, 60000))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn calories(rng: &mut Rng, elves: usize) -> String {
+    let mut calories = vec![];
+    write_calories(rng, elves, &mut calories).unwrap();
+    String::from_utf8(calories).unwrap()
+}
+
+pub fn strategy_guide(rng: &mut Rng, rounds: usize) -> String {
+    let mut guide = String::new();
+
+    for _round in 0..rounds {
+        guide.push(*rng.choose(&['A', 'B', 'C']));
+        guide.push(' ');
+        guide.push(*rng.choose(&['X', 'Y', 'Z']));
+        guide.push('\n');
+    }
+
+    guide
+}
+
+fn rucksack(rng: &mut Rng, pool: &[u8], badge: u8) -> String {
+    let half_len = rng.range(4, 24);
+    let shared = *rng.choose(pool);
+
+    let mut sides: [Vec<u8>; 2] = [vec![], vec![]];
+    for item in pool {
+        if *item != shared {
+            sides[rng.range(0, 1)].push(*item);
+        }
+    }
+
+    for side in &mut sides {
+        if side.is_empty() {
+            side.push(shared);
+        }
+    }
+
+    let badge_side = sides.iter().position(|side| side.contains(&badge));
+
+    let mut halves: [Vec<u8>; 2] = [vec![shared], vec![shared]];
+    for (index, half) in halves.iter_mut().enumerate() {
+        if badge_side == Some(index) {
+            half.push(badge);
+        }
+
+        while half.len() < half_len {
+            half.push(*rng.choose(&sides[index]));
+        }
+
+        rng.shuffle(half);
+    }
+
+    String::from_utf8([halves[0].clone(), halves[1].clone()].concat()).unwrap()
+}
+
+pub fn rucksacks(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _group in 0..size.div_ceil(3).max(1) {
+        let mut letters = LETTERS.to_vec();
+        rng.shuffle(&mut letters);
+
+        let badge = letters.pop().unwrap();
+        for pool in letters.chunks(letters.len() / 3) {
+            let mut pool = pool.to_vec();
+            pool.push(badge);
+
+            lines.push(rucksack(rng, &pool, badge));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub fn section_pairs(rng: &mut Rng, pairs: usize) -> String {
+    let mut lines = String::new();
+
+    for _pair in 0..pairs {
+        let mut range = || {
+            let min = rng.range(1, 99);
+            (min, rng.range(min, 99))
+        };
+
+        let (first_min, first_max) = range();
+        let (second_min, second_max) = range();
+
+        lines += &format!(
+            "{}-{},{}-{}\n",
+            first_min, first_max, second_min, second_max
+        );
+    }
+
+    lines
+}
+
+pub fn crate_moves(rng: &mut Rng, moves: usize) -> String {
+    let stack_count = 9;
+    let mut crate_stacks: Vec<Vec<u8>> = (0..stack_count)
+        .map(|_stack| {
+            (0..rng.range(1, 8 + moves / 50))
+                .map(|_crate| *rng.choose(&LETTERS[26..]))
+                .collect()
+        })
+        .collect();
+
+    let height = crate_stacks.iter().map(|stack| stack.len()).max().unwrap();
+    let mut drawing = vec![];
+
+    for level in (0..height).rev() {
+        let cells: Vec<String> = crate_stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(name) => format!("[{}]", *name as char),
+                None => "   ".to_string(),
+            })
+            .collect();
+
+        drawing.push(cells.join(" "));
+    }
+
+    let numbers: Vec<String> = (1..=stack_count)
+        .map(|number| format!(" {} ", number))
+        .collect();
+    drawing.push(numbers.join(" "));
+
+    let mut lines = drawing.join("\n") + "\n\n";
+
+    for _move in 0..moves {
+        let from = loop {
+            let from = rng.range(0, stack_count - 1);
+            if !crate_stacks[from].is_empty() {
+                break from;
+            }
+        };
+        let to = (from + rng.range(1, stack_count - 1)) % stack_count;
+        let amount = rng.range(1, crate_stacks[from].len().min(12));
+
+        let start = crate_stacks[from].len() - amount;
+        let mut moved = crate_stacks[from].split_off(start);
+        crate_stacks[to].append(&mut moved);
+
+        lines += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+    }
+
+    lines
+}
+
+pub fn datastream(rng: &mut Rng, length: usize) -> String {
+    let length = length.max(28);
+    let mut stream: Vec<u8> = (0..length)
+        .map(|index| match index % 2 {
+            0 => *rng.choose(&LETTERS[..4]),
+            _ => *rng.choose(&LETTERS[..26]),
+        })
+        .collect();
+
+    let mut marker = LETTERS[..26].to_vec();
+    rng.shuffle(&mut marker);
+
+    let start = rng.range(length / 2, length - 14);
+    stream[start..start + 14].copy_from_slice(&marker[..14]);
+
+    String::from_utf8(stream).unwrap() + "\n"
+}
+
+fn name(rng: &mut Rng, taken: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.range(1, 8))
+            .map(|_char| *rng.choose(&LETTERS[..26]) as char)
+            .collect();
+
+        if extension && rng.chance(1, 2) {
+            let extension = *rng.choose(&["txt", "dat", "log", "ext", "lst"]);
+            name.push('.');
+            name.push_str(extension);
+        }
+
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+struct Node {
+    directories: Vec<(String, usize)>,
+    files: Vec<(String, u64)>,
+}
+
+fn write_terminal(nodes: &[Node], index: usize, lines: &mut Vec<String>) {
+    let node = &nodes[index];
+    lines.push("$ ls".to_string());
+
+    for (name, _child) in &node.directories {
+        lines.push(format!("dir {}", name));
+    }
+
+    for (name, size) in &node.files {
+        lines.push(format!("{} {}", size, name));
+    }
+
+    for (name, child) in &node.directories {
+        lines.push(format!("$ cd {}", name));
+        write_terminal(nodes, *child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+pub fn terminal(rng: &mut Rng, directories: usize) -> String {
+    let mut nodes = vec![Node {
+        directories: vec![],
+        files: vec![],
+    }];
+    let mut names: Vec<HashSet<String>> = vec![HashSet::new()];
+
+    for child in 1..directories.max(1) {
+        let parent = rng.range(0, child - 1);
+        let name = name(rng, &mut names[parent], false);
+
+        nodes[parent].directories.push((name, child));
+        nodes.push(Node {
+            directories: vec![],
+            files: vec![],
+        });
+        names.push(HashSet::new());
+    }
+
+    let mut weights = vec![];
+    for (index, node) in nodes.iter_mut().enumerate() {
+        for _file in 0..rng.range(1, 4) {
+            let name = name(rng, &mut names[index], true);
+            let weight = rng.range(1, 1000) as u64;
+
+            weights.push(weight);
+            node.files.push((name, weight));
+        }
+    }
+
+    let total_weight: u64 = weights.iter().sum();
+    let target = rng.range(45_000_000, 65_000_000) as u64;
+
+    for node in &mut nodes {
+        for (_name, size) in &mut node.files {
+            *size = (*size * target / total_weight).max(1);
+        }
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    write_terminal(&nodes, 0, &mut lines);
+
+    lines.join("\n") + "\n"
+}
+
+pub fn tree_grid(rng: &mut Rng, side: usize) -> String {
+    let mut grid = String::new();
+
+    for _row in 0..side.max(1) {
+        for _column in 0..side.max(1) {
+            grid.push(char::from(b'0' + rng.range(0, 9) as u8));
+        }
+
+        grid.push('\n');
+    }
+
+    grid
+}
@@ -0,0 +1,103 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn load(&self, day: u8) -> anyhow::Result<String> {
+        match self {
+            InputSource::Path(path) => read_file(path, day),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read input from stdin")?;
+                Ok(input)
+            }
+            InputSource::Directory(directory) => read_file(&day_path(directory, day), day),
+        }
+    }
+
+    pub fn reader(&self, day: u8) -> anyhow::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Path(path) => open_file(path, day),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Directory(directory) => open_file(&day_path(directory, day), day),
+        }
+    }
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(inputs_directory())
+    }
+}
+
+pub fn inputs_directory() -> PathBuf {
+    match env::var_os(INPUTS_VAR) {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src"),
+    }
+}
+
+pub fn day_path(directory: &Path, day: u8) -> PathBuf {
+    directory.join(format!("day_{}", day)).join("input.txt")
+}
+
+fn check_exists(path: &Path, day: u8) -> anyhow::Result<()> {
+    if !path.is_file() {
+        bail!(
+            "No input for day {} at {:?}, pass an input path, `-` for stdin, or set {} to your inputs directory",
+            day,
+            path,
+            INPUTS_VAR
+        );
+    }
+
+    Ok(())
+}
+
+fn read_file(path: &Path, day: u8) -> anyhow::Result<String> {
+    check_exists(path, day)?;
+    fs::read_to_string(path).with_context(|| format!("Failed to read input {:?}", path))
+}
+
+fn open_file(path: &Path, day: u8) -> anyhow::Result<Box<dyn BufRead>> {
+    check_exists(path, day)?;
+    let file = File::open(path).with_context(|| format!("Failed to open input {:?}", path))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+pub fn source_from_args(day: u8) -> anyhow::Result<InputSource> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match &args[..] {
+        [] => Ok(InputSource::default()),
+        [arg] => Ok(InputSource::from_arg(arg)),
+        _ => bail!("Usage: day_{} [input|-]", day),
+    }
+}
+
+pub fn load_from_args(day: u8) -> anyhow::Result<String> {
+    source_from_args(day)?.load(day)
+}
@@ -0,0 +1,137 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+pub trait Integer: Copy + Ord + Debug + Display {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    fn distance(self, other: Self) -> u128;
+}
+
+macro_rules! integer {
+    ($($type:ty),*) => {
+        $(
+            impl Integer for $type {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn distance(self, other: Self) -> u128 {
+                    (other as i128 - self as i128).unsigned_abs()
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Option<Self> {
+        match start <= end {
+            true => Some(Interval { start, end }),
+            false => None,
+        }
+    }
+
+    pub fn point(value: T) -> Self {
+        Interval {
+            start: value,
+            end: value,
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn length(&self) -> u128 {
+        self.start.distance(self.end) + 1
+    }
+
+    pub fn contains_value(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn contains(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn touches(&self, other: &Interval<T>) -> bool {
+        self.overlaps(other)
+            || self.end.successor() == Some(other.start)
+            || other.end.successor() == Some(self.start)
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn hull(&self, other: &Interval<T>) -> Interval<T> {
+        Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    pub fn union(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        match self.touches(other) {
+            true => vec![self.hull(other)],
+            false => {
+                let mut pieces = vec![*self, *other];
+                pieces.sort();
+                pieces
+            }
+        }
+    }
+
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let mut pieces = vec![];
+
+        if self.start < other.start {
+            pieces.extend(
+                other
+                    .start
+                    .predecessor()
+                    .and_then(|end| Interval::new(self.start, end)),
+            );
+        }
+
+        if other.end < self.end {
+            pieces.extend(
+                other
+                    .end
+                    .successor()
+                    .and_then(|start| Interval::new(start, self.end)),
+            );
+        }
+
+        pieces
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+use crate::runner::Answer;
+
+pub const LEDGER_VAR: &str = "AOC_LEDGER";
+
+pub enum Verdict {
+    Unknown,
+    Correct,
+    Incorrect(String),
+}
+
+pub struct Ledger {
+    path: PathBuf,
+    entries: BTreeMap<(u8, u8, u64), String>,
+}
+
+pub fn ledger_path() -> PathBuf {
+    match env::var_os(LEDGER_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("ledger.tsv"),
+    }
+}
+
+pub fn hash_input(input: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash
+}
+
+impl Ledger {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let mut entries = BTreeMap::new();
+
+        if path.exists() {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read ledger {:?}", path))?;
+
+            for (index, line) in contents.lines().enumerate() {
+                let fields: Vec<&str> = line.splitn(4, '\t').collect();
+
+                let [day, part, hash, answer] = fields[..] else {
+                    bail!("Malformed ledger entry on line {} of {:?}", index + 1, path);
+                };
+
+                let day = day.parse()?;
+                let part = part.parse()?;
+                let hash = u64::from_str_radix(hash, 16)?;
+
+                entries.insert((day, part, hash), answer.to_string());
+            }
+        }
+
+        Ok(Ledger {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut contents = String::new();
+
+        for ((day, part, hash), answer) in &self.entries {
+            contents += &format!("{}\t{}\t{:016x}\t{}\n", day, part, hash, answer);
+        }
+
+        fs::write(&self.path, contents)
+            .with_context(|| format!("Failed to write ledger {:?}", self.path))
+    }
+
+    pub fn record(&mut self, answer: &Answer, hash: u64) {
+        self.entries
+            .insert((answer.day, answer.part, hash), answer.answer.clone());
+    }
+
+    pub fn check(&self, answer: &Answer, hash: u64) -> Verdict {
+        match self.entries.get(&(answer.day, answer.part, hash)) {
+            None => Verdict::Unknown,
+            Some(expected) if *expected == answer.answer => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect(expected.clone()),
+        }
+    }
+}
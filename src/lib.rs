@@ -0,0 +1,19 @@
+pub mod bench;
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod generate;
+pub mod input;
+pub mod interval;
+pub mod ledger;
+pub mod output;
+pub mod parse;
+pub mod runner;
+pub mod solution;
+
+pub use crate::solution::{run, Solution};
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+
+use crate::runner::Answer;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(format: &str) -> anyhow::Result<Self> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("Unknown format {:?}, expected text or json", format),
+        }
+    }
+}
+
+pub fn json_string(string: &str) -> String {
+    let mut escaped = String::from('"');
+
+    for char in string.chars() {
+        match char {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            char if char.is_control() => escaped += &format!("\\u{:04x}", char as u32),
+            char => escaped.push(char),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+pub fn json_record(answer: &Answer) -> String {
+    format!(
+        "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {}}}",
+        answer.day,
+        answer.part,
+        json_string(&answer.answer),
+        answer.elapsed.as_secs_f64()
+    )
+}
+
+pub fn json_error(day: u8, error: &str) -> String {
+    format!("{{\"day\": {}, \"error\": {}}}", day, json_string(error))
+}
+
+pub fn json_array(records: &[String]) -> String {
+    if records.is_empty() {
+        return "[]".to_string();
+    }
+
+    let records: Vec<String> = records
+        .iter()
+        .map(|record| format!("  {}", record))
+        .collect();
+
+    format!("[\n{}\n]", records.join(",\n"))
+}
+
+pub fn json_records(answers: &[Answer]) -> String {
+    json_array(&answers.iter().map(json_record).collect::<Vec<String>>())
+}
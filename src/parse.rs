@@ -0,0 +1,87 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        write!(
+            f,
+            "Day {}, line {}, column {}: {}\n{} |\n{} | {}\n{} | {}^",
+            self.day,
+            self.line,
+            self.column,
+            self.message,
+            gutter,
+            self.line,
+            self.text,
+            gutter,
+            " ".repeat(self.column.saturating_sub(1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column,
+            text: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn column_of(&self, field: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (field.as_ptr() as usize).wrapping_sub(start);
+
+        match self.text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        }
+    }
+
+    pub fn error_at(&self, field: &str, message: impl Into<String>) -> ParseError {
+        self.error(self.column_of(field), message)
+    }
+
+    pub fn parse<T>(&self, field: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        field
+            .parse()
+            .map_err(|error| self.error_at(field, format!("{} ({:?})", error, field)))
+    }
+}
+
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(index, text)| Line {
+        day,
+        number: index + 1,
+        text,
+    })
+}
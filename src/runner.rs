@@ -0,0 +1,127 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail};
+
+use crate::{
+    bench::{bench, Benchmark},
+    day_1::Day1,
+    day_2::Day2,
+    day_3::Day3,
+    day_4::Day4,
+    day_5::Day5,
+    day_6::Day6,
+    day_7::Day7,
+    day_8::Day8,
+    Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn includes(&self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Parts::One, 1) | (Parts::Two, 2) | (Parts::Both, 1 | 2)
+        )
+    }
+}
+
+impl FromStr for Parts {
+    type Err = anyhow::Error;
+
+    fn from_str(part: &str) -> anyhow::Result<Self> {
+        match part {
+            "1" => Ok(Parts::One),
+            "2" => Ok(Parts::Two),
+            "both" => Ok(Parts::Both),
+            _ => bail!("Unknown part {:?}, expected 1, 2 or both", part),
+        }
+    }
+}
+
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub label: &'static str,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+pub trait Runner {
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, parts: Parts) -> anyhow::Result<Vec<Answer>>;
+    fn bench(&self, input: &str, runs: usize) -> anyhow::Result<Benchmark>;
+}
+
+fn answer<S: Solution>(
+    part: u8,
+    label: &'static str,
+    answer: impl Display,
+    elapsed: Duration,
+) -> Answer {
+    Answer {
+        day: S::DAY,
+        part,
+        label,
+        answer: answer.to_string(),
+        elapsed,
+    }
+}
+
+impl<S: Solution> Runner for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: Parts) -> anyhow::Result<Vec<Answer>> {
+        let input = S::parse(input)?;
+        let mut answers = vec![];
+
+        if parts.includes(1) {
+            let start = Instant::now();
+            let part_one = S::part_one(&input)?;
+            answers.push(answer::<S>(1, S::PART_ONE, part_one, start.elapsed()));
+        }
+
+        if parts.includes(2) {
+            let start = Instant::now();
+            let part_two = S::part_two(&input)?;
+            answers.push(answer::<S>(2, S::PART_TWO, part_two, start.elapsed()));
+        }
+
+        Ok(answers)
+    }
+
+    fn bench(&self, input: &str, runs: usize) -> anyhow::Result<Benchmark> {
+        bench::<S>(input, runs)
+    }
+}
+
+pub fn days() -> Vec<Box<dyn Runner>> {
+    vec![
+        Box::new(Day1),
+        Box::new(Day2),
+        Box::new(Day3),
+        Box::new(Day4),
+        Box::new(Day5),
+        Box::new(Day6),
+        Box::new(Day7),
+        Box::new(Day8),
+    ]
+}
+
+pub fn get_day(day: u8) -> anyhow::Result<Box<dyn Runner>> {
+    days()
+        .into_iter()
+        .find(|runner| runner.day() == day)
+        .ok_or_else(|| anyhow!("Day {} is not implemented", day))
+}
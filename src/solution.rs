@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+pub trait Solution {
+    const DAY: u8;
+    const PART_ONE: &'static str;
+    const PART_TWO: &'static str;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> anyhow::Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> anyhow::Result<Self::PartTwo>;
+}
+
+pub fn run<S: Solution>(input: &str) -> anyhow::Result<()> {
+    let input = S::parse(input)?;
+
+    println!("{}: {}", S::PART_ONE, S::part_one(&input)?);
+    println!("{}: {}", S::PART_TWO, S::part_two(&input)?);
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use advent_of_code::{
+    day_1::{
+        balance::{balance, exact, greedy, lower_bound, Method, EXACT_LIMIT},
+        rank,
+        stats::Summary,
+        stream_top_elves, top_elves, Day1, ElfTotal,
+    },
+    generate::Rng,
+    Solution,
+};
+
+fn example() -> Vec<Vec<u32>> {
+    Day1::parse(include_str!("../src/day_1/example.txt")).unwrap()
+}
+
+#[test]
+fn top_elves_are_sorted_with_their_indices() {
+    assert_eq!(
+        top_elves(&example(), 3),
+        vec![
+            ElfTotal {
+                index: 3,
+                total: 24000
+            },
+            ElfTotal {
+                index: 2,
+                total: 11000
+            },
+            ElfTotal {
+                index: 4,
+                total: 10000
+            },
+        ]
+    );
+}
+
+#[test]
+fn top_elves_handles_fewer_elves_than_requested() {
+    let elves = vec![vec![1, 2], vec![5]];
+
+    assert_eq!(top_elves(&elves, 10).len(), 2);
+    assert_eq!(top_elves(&elves, 0), vec![]);
+    assert_eq!(Day1::part_two(&elves).unwrap(), 8);
+}
+
+#[test]
+fn ties_keep_the_earliest_elf_first() {
+    let elves = vec![vec![3], vec![5], vec![5]];
+
+    let indices: Vec<usize> = top_elves(&elves, 2).iter().map(|elf| elf.index).collect();
+    assert_eq!(indices, vec![1, 2]);
+    assert_eq!(rank(&elves, 2), Some(1));
+    assert_eq!(rank(&elves, 0), Some(3));
+}
+
+#[test]
+fn ranks_elves_by_total() {
+    let elves = example();
+
+    assert_eq!(rank(&elves, 3), Some(1));
+    assert_eq!(rank(&elves, 1), Some(5));
+    assert_eq!(rank(&elves, 5), None);
+}
+
+#[test]
+fn streaming_matches_parsed_totals() {
+    let input = include_str!("../src/day_1/example.txt");
+
+    assert_eq!(
+        stream_top_elves(input.as_bytes(), 4).unwrap(),
+        top_elves(&example(), 4)
+    );
+}
+
+#[test]
+fn blank_line_runs_do_not_add_elves() {
+    let input = "\n1000\n2000\n\n\n3000\n \n\n";
+    let elves = Day1::parse(input).unwrap();
+
+    assert_eq!(elves, [vec![1000, 2000], vec![3000]]);
+    assert_eq!(Summary::new(&elves).unwrap().elves, 2);
+    assert_eq!(
+        stream_top_elves(input.as_bytes(), 3).unwrap(),
+        top_elves(&elves, 3)
+    );
+    assert_eq!(
+        top_elves(&elves, 3),
+        [
+            ElfTotal {
+                index: 0,
+                total: 3000
+            },
+            ElfTotal {
+                index: 1,
+                total: 3000
+            }
+        ]
+    );
+    assert!(Day1::parse("\n\n").unwrap().is_empty());
+}
+
+#[test]
+fn summarises_the_example() {
+    let summary = Summary::new(&example()).unwrap();
+
+    assert_eq!(summary.elves, 5);
+    assert_eq!((summary.min, summary.max), (4000, 24000));
+    assert_eq!(summary.mean, 11000.0);
+    assert_eq!(summary.median, 10000.0);
+    assert_eq!(
+        summary.percentiles,
+        vec![
+            (10, 4000),
+            (25, 6000),
+            (50, 10000),
+            (75, 11000),
+            (90, 24000),
+            (99, 24000)
+        ]
+    );
+    assert_eq!(
+        summary.items_per_elf.into_iter().collect::<Vec<_>>(),
+        vec![(1, 2), (2, 1), (3, 2)]
+    );
+    assert_eq!(
+        summary.outliers,
+        vec![ElfTotal {
+            index: 3,
+            total: 24000
+        }]
+    );
+}
+
+#[test]
+fn histogram_covers_every_elf() {
+    let elves: Vec<Vec<u32>> = (0..37).map(|elf| vec![elf * 13 % 101]).collect();
+    let summary = Summary::new(&elves).unwrap();
+
+    assert_eq!(
+        summary
+            .histogram
+            .iter()
+            .map(|bucket| bucket.count)
+            .sum::<usize>(),
+        37
+    );
+    assert_eq!(summary.histogram[0].min, summary.min);
+    assert!(summary.histogram.last().unwrap().max >= summary.max);
+}
+
+#[test]
+fn single_elf_has_one_bucket() {
+    let summary = Summary::new(&[vec![5, 5]]).unwrap();
+
+    assert_eq!(summary.histogram.len(), 1);
+    assert_eq!(summary.median, 10.0);
+    assert!(summary.outliers.is_empty());
+    assert!(Summary::new(&[]).is_none());
+}
+
+fn brute_force_max_load(items: &[u32], elves: usize) -> u64 {
+    let mut best = u64::MAX;
+
+    for mut code in 0..elves.pow(items.len() as u32) {
+        let mut loads = vec![0; elves];
+
+        for item in items {
+            loads[code % elves] += *item as u64;
+            code /= elves;
+        }
+
+        best = best.min(*loads.iter().max().unwrap());
+    }
+
+    best
+}
+
+#[test]
+fn balances_the_example() {
+    let items = example().concat();
+    let partition = balance(&items, 3, Method::Auto).unwrap();
+
+    assert_eq!(partition.max_load(), 19000);
+    assert_eq!(partition.imbalance(), 1000);
+    assert_eq!(partition.bins.concat().len(), items.len());
+    assert_eq!(lower_bound(&items, 3).unwrap(), 18334);
+
+    assert!(lower_bound(&items, 0).is_err());
+    assert!(balance(&items, 0, Method::Greedy).is_err());
+    assert!(exact(&items, 0).is_err());
+
+    let many = vec![1000; 30];
+    assert!(EXACT_LIMIT < many.len());
+    assert!(balance(&many, 7, Method::Exact).is_err());
+    assert_eq!(balance(&many, 7, Method::Auto).unwrap().max_load(), 5000);
+}
+
+#[test]
+fn exact_balance_is_optimal() {
+    let mut rng = Rng::new(14);
+
+    for _case in 0..40 {
+        let items: Vec<u32> = (0..rng.range(1, 8))
+            .map(|_item| rng.range(1, 50) as u32)
+            .collect();
+        let elves = rng.range(1, 3);
+
+        let exact = exact(&items, elves).unwrap();
+        let greedy = greedy(&items, elves);
+
+        assert_eq!(exact.max_load(), brute_force_max_load(&items, elves));
+        assert!(exact.max_load() <= greedy.max_load());
+        assert!(greedy.max_load() >= lower_bound(&items, elves).unwrap());
+
+        for partition in [exact, greedy] {
+            let mut assigned = partition.bins.concat();
+            let mut expected = items.clone();
+            assigned.sort();
+            expected.sort();
+            assert_eq!(assigned, expected);
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use advent_of_code::{
+    day_2::{
+        decrypt::{interpretations, Meaning},
+        game::{Game, Outcome, ROCK_PAPER_SCISSORS_LIZARD_SPOCK},
+        rules::Rules,
+        simulate::{play, simulate, to_csv, Strategy},
+        Actions, Day2,
+    },
+    generate::generate,
+    Solution,
+};
+
+const ACTIONS: [Actions; 3] = [Actions::Rock, Actions::Paper, Actions::Scissors];
+
+#[test]
+fn rock_paper_scissors_matches_the_original_tables() {
+    let scores = [[3, 0, 6], [6, 3, 0], [0, 6, 3]];
+    let responses = [[2, 0, 1], [0, 1, 2], [1, 2, 0]];
+
+    for (you, action) in ACTIONS.iter().enumerate() {
+        assert_eq!(action.get_value(), you as u32 + 1);
+
+        for (opponent, other) in ACTIONS.iter().enumerate() {
+            assert_eq!(action.get_score(other), scores[you][opponent]);
+            assert_eq!(action.get_action(other).index(), responses[you][opponent]);
+        }
+    }
+}
+
+#[test]
+fn every_move_beats_half_of_the_others() {
+    for moves in [3, 5, 7, 9] {
+        let game = Game::new(moves).unwrap();
+
+        for you in 0..moves {
+            let outcomes: Vec<Outcome> = (0..moves)
+                .map(|opponent| game.get_outcome(you, opponent))
+                .collect();
+
+            let count = |outcome| outcomes.iter().filter(|other| **other == outcome).count();
+            assert_eq!(count(Outcome::Win), moves / 2);
+            assert_eq!(count(Outcome::Lose), moves / 2);
+            assert_eq!(count(Outcome::Draw), 1);
+
+            for opponent in 0..moves {
+                assert_eq!(
+                    game.get_outcome(you, opponent) == Outcome::Win,
+                    game.get_outcome(opponent, you) == Outcome::Lose
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn responses_reach_the_requested_outcome() {
+    let game = Game::new(5).unwrap();
+
+    for opponent in 0..5 {
+        for outcome in Outcome::ALL {
+            let response = game.get_response(opponent, outcome);
+            assert_eq!(game.get_outcome(response, opponent), outcome);
+        }
+    }
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let game = Game::new(5).unwrap();
+    let find = |name| {
+        ROCK_PAPER_SCISSORS_LIZARD_SPOCK
+            .iter()
+            .position(|other| *other == name)
+            .unwrap()
+    };
+
+    for (winner, loser) in [
+        ("Scissors", "Paper"),
+        ("Paper", "Rock"),
+        ("Rock", "Lizard"),
+        ("Lizard", "Spock"),
+        ("Spock", "Scissors"),
+        ("Scissors", "Lizard"),
+        ("Lizard", "Paper"),
+        ("Paper", "Spock"),
+        ("Spock", "Rock"),
+        ("Rock", "Scissors"),
+    ] {
+        assert_eq!(game.get_outcome(find(winner), find(loser)), Outcome::Win);
+    }
+}
+
+#[test]
+fn games_need_an_odd_number_of_moves() {
+    assert!(Game::new(1).is_err());
+    assert!(Game::new(4).is_err());
+    assert!(Game::new(7).is_ok());
+}
+
+#[test]
+fn default_rules_match_the_puzzle() {
+    let rules = Rules::default();
+
+    for seed in 0..10 {
+        let input = generate(2, 200, seed).unwrap();
+        let parsed = Day2::parse(&input).unwrap();
+        let rounds = rules.parse_guide(Day2::DAY, &input).unwrap();
+
+        assert_eq!(
+            rules.action_total(&rounds),
+            Day2::part_one(&parsed).unwrap()
+        );
+        assert_eq!(
+            rules.outcome_total(&rounds).unwrap(),
+            Day2::part_two(&parsed).unwrap()
+        );
+    }
+}
+
+#[test]
+fn rules_files_change_encoding_and_scores() {
+    let rules = Rules::parse(
+        "# Lizard Spock tournament\n\
+         opponent = A B C D E\n\
+         response = V W X Y Z\n\
+         outcome = X Y Z # lose, draw, win\n\
+         values = 5, 4, 3, 2, 1\n\
+         outcomes = 0 1 2\n",
+    )
+    .unwrap();
+
+    let rounds = rules.parse_guide(2, "A W\nE X\nC Z\n").unwrap();
+    assert_eq!(rounds, vec![(0, 1), (4, 2), (2, 4)]);
+    assert_eq!(rules.action_total(&rounds), 6 + 3 + 3);
+    assert!(rules.outcome_total(&rounds).is_err());
+
+    let rounds = rules.parse_guide(2, "A X\nB Y\nC Z\n").unwrap();
+    assert_eq!(rules.outcome_total(&rounds).unwrap(), 1 + 5 + 4);
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    assert!(Rules::parse("opponent = A B C D\n").is_err());
+    assert!(Rules::parse("opponent = A B C D E\n").is_err());
+    assert!(Rules::parse("response = X X Z\n").is_err());
+    assert!(Rules::parse("outcomes = 0 3\n").is_err());
+    assert!(Rules::parse("colour = red\n").is_err());
+    assert!(Rules::parse("values 1 2 3\n").is_err());
+    assert!(Rules::parse("outcome = X Y Q\n").is_err());
+}
+
+#[test]
+fn guides_are_checked_against_the_rules() {
+    let mut rules = Rules::default();
+    rules.set("opponent", "R P S").unwrap();
+    rules.validate().unwrap();
+
+    let error = rules.parse_guide(2, "R X\nA Y\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.message, "Expected an opponent move of R, P or S");
+}
+
+#[test]
+fn decryption_covers_every_interpretation() {
+    let rules = Rules::default();
+    let input = include_str!("../src/day_2/example.txt");
+    let rounds = rules.parse_guide(Day2::DAY, input).unwrap();
+    let interpretations = interpretations(&rules, &rounds).unwrap();
+
+    assert_eq!(interpretations.len(), 12);
+    for (index, interpretation) in interpretations.iter().enumerate() {
+        assert!(interpretations[..index]
+            .iter()
+            .all(|other| other.meaning != interpretation.meaning));
+    }
+
+    assert_eq!(interpretations[0].score, 24);
+    assert_eq!(interpretations[11].score, 6);
+    assert_eq!(
+        Meaning::part_one(&rules).get_total(&rules, &rounds),
+        rules.action_total(&rounds)
+    );
+    assert_eq!(
+        Meaning::part_two(&rules)
+            .unwrap()
+            .get_total(&rules, &rounds),
+        rules.outcome_total(&rounds).unwrap()
+    );
+    assert_eq!(
+        Meaning::part_one(&rules).describe(&rules),
+        "X=Rock Y=Paper Z=Scissors"
+    );
+}
+
+#[test]
+fn decryption_uses_the_loaded_rules() {
+    let mut rules = Rules::default();
+    rules.set("values", "10,20,30").unwrap();
+    rules.set("outcomes", "1,2,3").unwrap();
+    rules.validate().unwrap();
+
+    let input = include_str!("../src/day_2/example.txt");
+    let rounds = rules.parse_guide(Day2::DAY, input).unwrap();
+    let ranked = interpretations(&rules, &rounds).unwrap();
+
+    assert_eq!(ranked.len(), 12);
+    assert_eq!(ranked[0].score, 96);
+    assert_eq!(ranked[0].meaning.describe(&rules), "X=Win Y=Lose Z=Draw");
+
+    let part_one = Meaning::part_one(&rules);
+    let identity = ranked
+        .iter()
+        .find(|interpretation| interpretation.meaning == part_one)
+        .unwrap();
+    assert_eq!(identity.score, 20 + 3 + 10 + 1 + 30 + 2);
+    assert_eq!(identity.score, rules.action_total(&rounds));
+
+    let mut rules = Rules::default();
+    rules.set("opponent", "A,B,C,D,E").unwrap();
+    rules.set("response", "V,W,X,Y,Z").unwrap();
+    rules.set("values", "1,2,3,4,5").unwrap();
+    rules.validate().unwrap();
+
+    let rounds = rules.parse_guide(Day2::DAY, "A V\nE X\nC Z\n").unwrap();
+    let ranked = interpretations(&rules, &rounds).unwrap();
+
+    assert_eq!(ranked.len(), 120);
+    assert!(Meaning::part_two(&rules).is_none());
+    assert!(ranked
+        .iter()
+        .all(|interpretation| matches!(interpretation.meaning, Meaning::Moves(_))));
+    assert_eq!(
+        Meaning::part_one(&rules).get_total(&rules, &rounds),
+        rules.action_total(&rounds)
+    );
+    assert_eq!(
+        Meaning::part_one(&rules).describe(&rules),
+        "V=Rock W=Spock X=Paper Y=Lizard Z=Scissors"
+    );
+}
+
+#[test]
+fn replay_tracks_running_scores_for_both_interpretations() {
+    let rules = Rules::default();
+    let rounds = rules
+        .parse_guide(Day2::DAY, include_str!("../src/day_2/example.txt"))
+        .unwrap();
+    let moves = simulate(&rules, &rounds, &Meaning::part_one(&rules)).unwrap();
+    let outcomes = simulate(&rules, &rounds, &Meaning::part_two(&rules).unwrap()).unwrap();
+
+    assert_eq!(
+        moves.iter().map(|round| round.points).collect::<Vec<_>>(),
+        [8, 1, 6]
+    );
+    assert_eq!(
+        outcomes
+            .iter()
+            .map(|round| round.running_score)
+            .collect::<Vec<_>>(),
+        [4, 5, 12]
+    );
+    assert_eq!(outcomes[2].you, 0);
+    assert_eq!(outcomes[2].outcome, Outcome::Win);
+
+    let csv = to_csv(&rules, &[("moves", moves), ("outcomes", outcomes)]);
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines[0],
+        "interpretation,round,opponent,you,outcome,points,running_score"
+    );
+    assert_eq!(lines[1], "moves,1,Rock,Paper,Win,8,8");
+    assert_eq!(lines[6], "outcomes,3,Scissors,Rock,Win,7,12");
+    assert_eq!(lines.len(), 7);
+}
+
+#[test]
+fn replay_uses_the_loaded_rules() {
+    let mut rules = Rules::default();
+    rules.set("values", "10,20,30").unwrap();
+    rules.validate().unwrap();
+
+    let rounds = rules
+        .parse_guide(Day2::DAY, include_str!("../src/day_2/example.txt"))
+        .unwrap();
+    let moves = simulate(&rules, &rounds, &Meaning::part_one(&rules)).unwrap();
+    let outcomes = simulate(&rules, &rounds, &Meaning::part_two(&rules).unwrap()).unwrap();
+
+    assert_eq!(moves[0].points, 20 + 6);
+    assert_eq!(
+        moves.last().unwrap().running_score,
+        rules.action_total(&rounds)
+    );
+    assert_eq!(
+        outcomes.last().unwrap().running_score,
+        rules.outcome_total(&rounds).unwrap()
+    );
+
+    let csv = to_csv(&rules, &[("moves", moves)]);
+    assert_eq!(csv.lines().nth(1), Some("moves,1,Rock,Paper,Win,26,26"));
+
+    let mut rules = Rules::default();
+    rules.set("opponent", "A,B,C,D,E").unwrap();
+    rules.set("response", "V,W,X,Y,Z").unwrap();
+    rules.set("values", "1,2,3,4,5").unwrap();
+    rules.validate().unwrap();
+
+    let rounds = rules.parse_guide(Day2::DAY, "A V\nE X\nC Z\n").unwrap();
+    let moves = simulate(&rules, &rounds, &Meaning::part_one(&rules)).unwrap();
+
+    assert_eq!(
+        moves.last().unwrap().running_score,
+        rules.action_total(&rounds)
+    );
+    assert_eq!(
+        to_csv(&rules, &[("moves", moves)]).lines().nth(2),
+        Some("moves,2,Scissors,Paper,Lose,3,7")
+    );
+}
+
+#[test]
+fn replay_against_the_guide_matches_both_parts() {
+    let rules = Rules::default();
+
+    for seed in 0..10 {
+        let rounds = rules
+            .parse_guide(Day2::DAY, &generate(2, 200, seed).unwrap())
+            .unwrap();
+        let moves = simulate(&rules, &rounds, &Meaning::part_one(&rules)).unwrap();
+        let outcomes = simulate(&rules, &rounds, &Meaning::part_two(&rules).unwrap()).unwrap();
+
+        assert_eq!(
+            moves.last().unwrap().running_score,
+            rules.action_total(&rounds)
+        );
+        assert_eq!(
+            outcomes.last().unwrap().running_score,
+            rules.outcome_total(&rounds).unwrap()
+        );
+    }
+}
+
+#[test]
+fn opponent_strategies() {
+    let rules = Rules::default();
+    let moves = Meaning::part_one(&rules);
+    let rounds = vec![(0, 0); 6];
+
+    let cycle = play(&rules, &rounds, &moves, &"cycle:rps".parse().unwrap()).unwrap();
+    assert_eq!(
+        cycle.iter().map(|round| round.opponent).collect::<Vec<_>>(),
+        [0, 1, 2, 0, 1, 2]
+    );
+    assert_eq!(cycle.last().unwrap().running_score, 2 * (4 + 1 + 7));
+
+    let named = play(&rules, &rounds, &moves, &"cycle:rock,2,sc".parse().unwrap()).unwrap();
+    assert!(named
+        .iter()
+        .zip(&cycle)
+        .all(|(first, second)| first.opponent == second.opponent));
+
+    let frequency = play(&rules, &rounds, &moves, &Strategy::Frequency).unwrap();
+    assert!(frequency[1..]
+        .iter()
+        .all(|round| round.opponent == 1 && round.outcome == Outcome::Lose));
+
+    let random = play(&rules, &rounds, &moves, &Strategy::Random(7)).unwrap();
+    let again = play(&rules, &rounds, &moves, &"random:7".parse().unwrap()).unwrap();
+    assert!(random
+        .iter()
+        .zip(&again)
+        .all(|(first, second)| first.opponent == second.opponent));
+
+    assert!(play(&rules, &rounds, &moves, &"cycle:RPX".parse().unwrap()).is_err());
+    assert!(play(&rules, &rounds, &moves, &"cycle:1,4".parse().unwrap()).is_err());
+    assert!("cycle:".parse::<Strategy>().is_err());
+    assert!("mirror".parse::<Strategy>().is_err());
+
+    let mut rules = Rules::default();
+    rules.set("opponent", "A,B,C,D,E").unwrap();
+    rules.set("response", "V,W,X,Y,Z").unwrap();
+    rules.set("values", "1,2,3,4,5").unwrap();
+    rules.validate().unwrap();
+
+    let moves = Meaning::part_one(&rules);
+    let cycle = play(&rules, &rounds, &moves, &"cycle:spock,liz".parse().unwrap()).unwrap();
+    assert_eq!(cycle[0].opponent, 1);
+    assert_eq!(cycle[1].opponent, 3);
+    assert!(play(&rules, &rounds, &moves, &"cycle:s".parse().unwrap()).is_err());
+}
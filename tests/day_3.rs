@@ -0,0 +1,276 @@
+use advent_of_code::{
+    day_3::{
+        alphabet::Alphabet,
+        items::ItemSet,
+        layout::Layout,
+        naive,
+        repack::{plan, plan_group, plan_rucksack, Move, Swap},
+        Day3, ALPHABET,
+    },
+    generate::generate,
+    Solution,
+};
+
+#[test]
+fn item_priorities() {
+    let alphabet = Alphabet::default();
+
+    for (index, item) in ALPHABET.chars().enumerate() {
+        assert_eq!(alphabet.get_priority(item), Some(index + 1));
+        assert_eq!(
+            alphabet.get_item(alphabet.get_slot(item).unwrap()),
+            Some(item)
+        );
+    }
+
+    assert_eq!(alphabet.get_priority('1'), None);
+    assert_eq!(alphabet.get_priority('é'), None);
+    assert_eq!(alphabet.get_item(0), None);
+    assert_eq!(alphabet.get_item(53), None);
+}
+
+#[test]
+fn item_set_operations() {
+    let alphabet = Alphabet::default();
+    let first = alphabet.item_set("vJrwpWtwJgWr");
+    let second = alphabet.item_set("hcsFMMfFFhFp");
+    let slot = |item| alphabet.get_slot(item).unwrap();
+
+    assert_eq!(first.len(), 8);
+    assert!(first.contains(slot('W')) && !first.contains(slot('a')));
+    assert_eq!(first.intersection(second), alphabet.item_set("p"));
+    assert_eq!(alphabet.priority_sum(first.intersection(second)), 16);
+    assert_eq!(first.union(second).len(), 14);
+    assert_eq!(
+        alphabet.items(first.union(second)).collect::<String>(),
+        "cfghprstvwFJMW"
+    );
+    assert!(first.intersection(alphabet.item_set("xyz")).is_empty());
+    assert!(alphabet.item_set("!1é").is_empty());
+
+    let mut set = ItemSet::EMPTY;
+    assert!(set.insert(3));
+    assert!(!set.insert(3));
+    assert!(set.contains(3) && !set.contains(4) && !set.contains(500));
+    assert_eq!(
+        set.union([1, 127].into_iter().collect())
+            .slots()
+            .collect::<Vec<_>>(),
+        [1, 3, 127]
+    );
+    assert_eq!(format!("{:?}", set), "ItemSet([3])");
+    assert_eq!(
+        alphabet.priority_sum(alphabet.item_set(ALPHABET)),
+        (1..=52).sum()
+    );
+}
+
+#[test]
+fn item_sets_agree_with_the_naive_search() {
+    for seed in 0..20 {
+        let lines = Day3::parse(&generate(3, 300, seed).unwrap()).unwrap();
+
+        assert_eq!(Day3::part_one(&lines).unwrap(), naive::error_sum(&lines));
+        assert_eq!(Day3::part_two(&lines).unwrap(), naive::group_sum(&lines));
+    }
+}
+
+fn rucksacks(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn layouts_with_more_compartments_and_smaller_groups() {
+    let layout = Layout::new(3, 2).unwrap();
+    let lines = rucksacks(&["aXbcXdeXf", "gXhiXjkXl", "mnZZopZqr", "ZstuvZwxZ"]);
+
+    assert_eq!(layout.get_misplaced(1, &lines[0]).unwrap(), 'X');
+    assert_eq!(layout.get_badge(3, &lines[2..]).unwrap(), 'Z');
+    assert_eq!(layout.error_sum(&lines).unwrap(), 50 + 50 + 52 + 52);
+    assert_eq!(layout.badge_sum(&lines).unwrap(), 50 + 52);
+
+    assert!(Layout::new(1, 3).is_err());
+    assert!(Layout::new(2, 1).is_err());
+    assert_eq!(Layout::default(), Layout::new(2, 3).unwrap());
+}
+
+#[test]
+fn layout_errors() {
+    let layout = Layout::default();
+    let example = Day3::parse(include_str!("../src/day_3/example.txt")).unwrap();
+
+    let error = |result: anyhow::Result<usize>| result.unwrap_err().to_string();
+
+    assert_eq!(
+        error(layout.error_sum(&rucksacks(&["abcd"]))),
+        "Rucksack on line 1 has no item common to all 2 compartments"
+    );
+    assert_eq!(
+        error(layout.error_sum(&rucksacks(&["abcb", "abcab"]))),
+        "Rucksack on line 2 has 5 items, which can't be split into 2 equal compartments"
+    );
+    assert_eq!(
+        error(layout.error_sum(&rucksacks(&["abab"]))),
+        "Rucksack on line 1 has 2 items common to all 2 compartments: ab"
+    );
+    assert_eq!(
+        error(layout.badge_sum(&example[..4])),
+        "Group on lines 4-4 has 1 rucksacks, expected 3"
+    );
+    assert_eq!(
+        error(Layout::new(2, 2).unwrap().badge_sum(&example)),
+        "Group on lines 1-2 has 5 possible badges: frsFM"
+    );
+    assert_eq!(
+        error(Layout::new(2, 6).unwrap().badge_sum(&example)),
+        "Group on lines 1-6 has no badge common to all 6 elves"
+    );
+}
+
+#[test]
+fn repacking_generated_rucksacks_moves_only_the_misplaced_item() {
+    let layout = Layout::default();
+
+    for seed in 0..10 {
+        let lines = Day3::parse(&generate(3, 60, seed).unwrap()).unwrap();
+
+        for line_plan in plan(&layout, &lines).unwrap() {
+            let line = &lines[line_plan.number - 1];
+
+            assert_eq!(line_plan.moves.len(), 1);
+            assert_eq!(
+                line_plan.moves[0].item,
+                layout.get_misplaced(line_plan.number, line).unwrap()
+            );
+            assert!(line_plan.swaps.is_empty());
+        }
+    }
+}
+
+#[test]
+fn repacking_keeps_each_item_in_its_fullest_compartment() {
+    let layout = Layout::new(3, 2).unwrap();
+    let moves = plan_rucksack(&layout, 1, "aabbcXaYcbYX").unwrap();
+
+    assert_eq!(
+        moves,
+        [
+            Move {
+                item: 'a',
+                count: 1,
+                from: 2,
+                to: 1
+            },
+            Move {
+                item: 'b',
+                count: 1,
+                from: 3,
+                to: 1
+            },
+            Move {
+                item: 'c',
+                count: 1,
+                from: 3,
+                to: 2
+            },
+            Move {
+                item: 'X',
+                count: 1,
+                from: 3,
+                to: 2
+            },
+            Move {
+                item: 'Y',
+                count: 1,
+                from: 3,
+                to: 2
+            },
+        ]
+    );
+    assert!(plan_rucksack(&layout, 1, "abcdef").unwrap().is_empty());
+}
+
+#[test]
+fn repacking_groups_leaves_exactly_one_badge() {
+    let layout = Layout::new(2, 3).unwrap();
+
+    let group = rucksacks(&["abcdxyzz", "bcaazzxq", "xaayzcbz"]);
+    assert_eq!(
+        plan_group(&layout, 1, &group).unwrap(),
+        [
+            vec![
+                Swap::Out('a', 1),
+                Swap::Out('b', 1),
+                Swap::Out('c', 1),
+                Swap::Out('x', 1)
+            ],
+            vec![],
+            vec![],
+        ]
+    );
+
+    let group = rucksacks(&["abcd", "efgh", "ijka"]);
+    assert_eq!(
+        plan_group(&layout, 1, &group).unwrap(),
+        [vec![], vec![Swap::In('a')], vec![]]
+    );
+
+    let plans = plan(&layout, &group).unwrap();
+    assert_eq!(plans[0].to_string(), "Line 1: nothing to do");
+    assert_eq!(plans[1].to_string(), "Line 2: swap in 1 a as the badge");
+    assert!(plan(&layout, &group[..2]).is_err());
+}
+
+#[test]
+fn extended_alphabets() {
+    let alphabet = Alphabet::parse("# Accented items\né = 53\nü = 54\n\na = 100\n").unwrap();
+
+    assert_eq!(alphabet.len(), 54);
+    assert_eq!(alphabet.get_priority('é'), Some(53));
+    assert_eq!(alphabet.get_priority('a'), Some(100));
+    assert_eq!(alphabet.get_priority('Z'), Some(52));
+    assert_eq!(alphabet.get_priority('ö'), None);
+    assert_eq!(alphabet.describe(), "a-z, A-Z or one of \"éü\"");
+    assert_eq!(Alphabet::default().describe(), "a-z or A-Z");
+
+    let set = alphabet.item_set("aéüé");
+    assert_eq!(set.len(), 3);
+    assert_eq!(alphabet.items(set).collect::<String>(), "aéü");
+    assert_eq!(alphabet.priority_sum(set), 100 + 53 + 54);
+
+    assert!(Alphabet::parse("é 53").is_err());
+    assert!(Alphabet::parse("éü = 53").is_err());
+    assert!(Alphabet::parse("é = 0").is_err());
+    assert!(Alphabet::parse("é = high").is_err());
+}
+
+#[test]
+fn layouts_split_unicode_rucksacks_by_item() {
+    let alphabet = Alphabet::parse("é = 53\nü = 54\nß = 55").unwrap();
+    let layout = Layout::default().with_alphabet(alphabet);
+    let input = "abcéxyzé\nüéaüpqrs\nüabcüdeß\n";
+
+    assert!(Day3::parse(input).is_err());
+
+    let lines = layout.parse(3, input).unwrap();
+    assert_eq!(
+        layout
+            .get_compartments(1, &lines[0])
+            .unwrap()
+            .collect::<Vec<_>>(),
+        ["abcé", "xyzé"]
+    );
+    assert_eq!(layout.get_misplaced(3, &lines[2]).unwrap(), 'ü');
+    assert!(layout.get_misplaced(2, &lines[1]).is_err());
+    assert_eq!(layout.badge_sum(&lines).unwrap(), 1);
+
+    let error = layout.parse(3, "abcé\nßßß\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+
+    let error = layout.parse(3, "abcö\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 4));
+    assert_eq!(
+        error.message,
+        "'ö' is not an item, expected a-z, A-Z or one of \"éüß\""
+    );
+}
@@ -0,0 +1,163 @@
+use advent_of_code::{
+    day_4::{coverage::Coverage, index::IntervalIndex, Day4},
+    generate::{generate, Rng},
+    interval::Interval,
+    Solution,
+};
+
+fn assignments(input: &str) -> Vec<Interval<u8>> {
+    Day4::parse(input)
+        .unwrap()
+        .iter()
+        .flat_map(|(first, second)| [*first, *second])
+        .collect()
+}
+
+#[test]
+fn coverage_of_the_example() {
+    let coverage = Coverage::new(&assignments(include_str!("../src/day_4/example.txt")));
+    let interval = |min, max| Interval::new(min, max).unwrap();
+
+    assert_eq!(
+        coverage
+            .segments
+            .iter()
+            .map(|segment| segment.depth)
+            .collect::<Vec<_>>(),
+        [4, 5, 7, 8, 6, 4, 1]
+    );
+    assert_eq!(coverage.segments[2].sections, interval(4, 5));
+    assert_eq!(coverage.max_depth(), 8);
+    assert_eq!(coverage.deepest(), [Interval::point(6)]);
+    assert_eq!(coverage.count_sections(|depth| depth == 1), 1);
+    assert_eq!(coverage.count_sections(|depth| depth > 1), 7);
+    assert!(coverage.get_sections(|depth| depth == 0).is_empty());
+    assert_eq!(coverage.get_sections(|depth| depth >= 5), [interval(3, 7)]);
+}
+
+#[test]
+fn coverage_matches_counting_every_section() {
+    for seed in 0..20 {
+        let assignments =
+            assignments(&generate(4, [1, 5, 50, 500][seed as usize % 4], seed).unwrap());
+        let coverage = Coverage::new(&assignments);
+
+        let mut depths = [0; 256];
+        for interval in &assignments {
+            for section in interval.start()..=interval.end() {
+                depths[section as usize] += 1;
+            }
+        }
+
+        let first = assignments
+            .iter()
+            .map(|interval| interval.start())
+            .min()
+            .unwrap();
+        let last = assignments
+            .iter()
+            .map(|interval| interval.end())
+            .max()
+            .unwrap();
+        let mut covered = vec![];
+
+        for segment in &coverage.segments {
+            for section in segment.sections.start()..=segment.sections.end() {
+                assert_eq!(
+                    depths[section as usize], segment.depth,
+                    "section {}",
+                    section
+                );
+                covered.push(section);
+            }
+        }
+
+        assert_eq!(covered, (first..=last).collect::<Vec<_>>());
+        assert_eq!(coverage.max_depth(), *depths.iter().max().unwrap());
+
+        for window in coverage.segments.windows(2) {
+            assert_ne!(window[0].depth, window[1].depth);
+        }
+    }
+}
+
+#[test]
+fn coverage_at_the_ends_of_the_type() {
+    let coverage = Coverage::new(&[
+        Interval::new(0u8, 255).unwrap(),
+        Interval::new(250, 255).unwrap(),
+        Interval::new(0, 3).unwrap(),
+    ]);
+
+    assert_eq!(coverage.segments.len(), 3);
+    assert_eq!(
+        coverage.deepest(),
+        [
+            Interval::new(0, 3).unwrap(),
+            Interval::new(250, 255).unwrap()
+        ]
+    );
+    assert_eq!(coverage.count_sections(|depth| depth == 1), 246);
+    assert!(Coverage::<u8>::new(&[]).segments.is_empty());
+}
+
+fn random_intervals(rng: &mut Rng, count: usize, limit: usize) -> Vec<Interval<u16>> {
+    (0..count)
+        .map(|_interval| {
+            let min = rng.range(0, limit);
+            let max = (min + rng.range(0, limit / 4)).min(limit);
+            Interval::new(min as u16, max as u16).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn index_matches_checking_every_assignment() {
+    let mut rng = Rng::new(7);
+
+    for count in [0, 1, 2, 3, 10, 33, 100, 500] {
+        let intervals = random_intervals(&mut rng, count, 1000);
+        let index = IntervalIndex::new(&intervals);
+
+        let mut pairs = 0;
+        for (position, first) in intervals.iter().enumerate() {
+            pairs += intervals[position + 1..]
+                .iter()
+                .filter(|second| first.overlaps(second))
+                .count() as u64;
+        }
+
+        assert_eq!(index.len(), count);
+        assert_eq!(index.count_overlapping_pairs(), pairs);
+
+        for query in random_intervals(&mut rng, 50, 1100) {
+            let mut expected: Vec<usize> = (0..count)
+                .filter(|position| intervals[*position].overlaps(&query))
+                .collect();
+            let found = index.overlapping(&query);
+
+            assert!(found.windows(2).all(|window| {
+                let (first, second) = (intervals[window[0]], intervals[window[1]]);
+                (first.start(), first.end()) <= (second.start(), second.end())
+            }));
+            assert_eq!(index.count_overlapping(&query), expected.len());
+
+            let mut found = found;
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected);
+        }
+    }
+}
+
+#[test]
+fn index_over_the_example() {
+    let example = assignments(include_str!("../src/day_4/example.txt"));
+    let index = IntervalIndex::new(&example);
+
+    assert_eq!(index.overlapping(&Interval::point(7)), [6, 7, 11, 4, 1, 5]);
+    assert!(index.overlapping(&Interval::point(1)).is_empty());
+    assert_eq!(index.count_overlapping(&Interval::new(8, 20).unwrap()), 4);
+    assert_eq!(index.get(5), Interval::new(7, 9));
+    assert_eq!(index.count_overlapping_pairs(), 49);
+}
@@ -0,0 +1,64 @@
+use advent_of_code::{
+    day_1::Day1, day_2::Day2, day_3::Day3, day_4::Day4, day_5::Day5, day_6::Day6, day_7::Day7,
+    day_8::Day8, Solution,
+};
+
+fn check<S: Solution>(input: &str, part_one: &str, part_two: &str) {
+    let input = S::parse(input).unwrap();
+
+    assert_eq!(S::part_one(&input).unwrap().to_string(), part_one);
+    assert_eq!(S::part_two(&input).unwrap().to_string(), part_two);
+}
+
+#[test]
+fn day_1() {
+    check::<Day1>(include_str!("../src/day_1/example.txt"), "24000", "45000");
+}
+
+#[test]
+fn day_2() {
+    check::<Day2>(include_str!("../src/day_2/example.txt"), "15", "12");
+}
+
+#[test]
+fn day_3() {
+    check::<Day3>(include_str!("../src/day_3/example.txt"), "157", "70");
+}
+
+#[test]
+fn day_4() {
+    check::<Day4>(include_str!("../src/day_4/example.txt"), "2", "4");
+}
+
+#[test]
+fn day_5() {
+    check::<Day5>(include_str!("../src/day_5/example.txt"), "CMZ", "MCD");
+}
+
+#[test]
+fn day_6() {
+    check::<Day6>(include_str!("../src/day_6/example.txt"), "7", "19");
+    check::<Day6>("bvwbjplbgvbhsrlpgdmjqwftvncz", "5", "23");
+    check::<Day6>("nppdvjthqldpwncqszvftbrmjlhg", "6", "23");
+    check::<Day6>("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", "10", "29");
+    check::<Day6>("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "11", "26");
+}
+
+#[test]
+fn day_7() {
+    check::<Day7>(
+        include_str!("../src/day_7/example.txt"),
+        "95437",
+        "24933642",
+    );
+    check::<Day7>("$ cd /\n$ ls\n", "0", "0");
+    check::<Day7>("1 a\n", "1", "0");
+
+    let full = Day7::parse("$ cd /\n$ ls\n80000000 a\n").unwrap();
+    assert!(Day7::part_two(&full).is_err());
+}
+
+#[test]
+fn day_8() {
+    check::<Day8>(include_str!("../src/day_8/example.txt"), "21", "8");
+}
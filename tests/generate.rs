@@ -0,0 +1,34 @@
+use advent_of_code::{
+    generate::{default_size, generate},
+    runner::{days, Parts},
+};
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for runner in days() {
+        let day = runner.day();
+
+        for seed in 0..10 {
+            let input = generate(day, default_size(day), seed).unwrap();
+
+            if let Err(error) = runner.run(&input, Parts::Both) {
+                panic!("Day {} with seed {} failed: {:#}", day, seed, error);
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_are_reproducible() {
+    for runner in days() {
+        let day = runner.day();
+
+        assert_eq!(generate(day, 50, 7).unwrap(), generate(day, 50, 7).unwrap());
+        assert_ne!(generate(day, 50, 7).unwrap(), generate(day, 50, 8).unwrap());
+    }
+}
+
+#[test]
+fn unknown_days_have_no_generator() {
+    assert!(generate(25, 10, 0).is_err());
+}
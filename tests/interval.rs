@@ -0,0 +1,101 @@
+use std::collections::BTreeSet;
+
+use advent_of_code::interval::Interval;
+
+fn sections(intervals: &[Interval<u8>]) -> BTreeSet<u8> {
+    intervals
+        .iter()
+        .flat_map(|interval| interval.start()..=interval.end())
+        .collect()
+}
+
+fn all_intervals(limit: u8) -> Vec<Interval<u8>> {
+    let mut intervals = vec![];
+
+    for min in 0..=limit {
+        for max in min..=limit {
+            intervals.push(Interval::new(min, max).unwrap());
+        }
+    }
+
+    intervals
+}
+
+#[test]
+fn operations_match_sets_of_sections() {
+    let intervals = all_intervals(7);
+
+    for first in &intervals {
+        let first_sections = sections(&[*first]);
+        assert_eq!(first.length(), first_sections.len() as u128);
+
+        for second in &intervals {
+            let second_sections = sections(&[*second]);
+
+            assert_eq!(
+                first.contains(second),
+                second_sections.is_subset(&first_sections)
+            );
+            assert_eq!(
+                first.overlaps(second),
+                !first_sections.is_disjoint(&second_sections)
+            );
+            assert_eq!(
+                sections(&first.intersection(second).into_iter().collect::<Vec<_>>()),
+                &first_sections & &second_sections
+            );
+            assert_eq!(
+                sections(&first.union(second)),
+                &first_sections | &second_sections
+            );
+            assert_eq!(
+                sections(&first.difference(second)),
+                &first_sections - &second_sections
+            );
+        }
+    }
+}
+
+#[test]
+fn pieces_are_sorted_and_disjoint() {
+    let interval = |min, max| Interval::new(min, max).unwrap();
+
+    assert_eq!(interval(2, 4).union(&interval(5, 8)), [interval(2, 8)]);
+    assert_eq!(
+        interval(6, 8).union(&interval(2, 4)),
+        [interval(2, 4), interval(6, 8)]
+    );
+    assert_eq!(
+        interval(1, 9).difference(&interval(3, 5)),
+        [interval(1, 2), interval(6, 9)]
+    );
+    assert!(interval(3, 5).difference(&interval(1, 9)).is_empty());
+
+    let mut pieces = vec![interval(6, 8), interval(2, 9), interval(2, 4)];
+    pieces.sort();
+    assert_eq!(pieces, [interval(2, 4), interval(2, 9), interval(6, 8)]);
+    assert_eq!((interval(2, 4).start(), interval(2, 4).end()), (2, 4));
+}
+
+#[test]
+fn intervals_reach_the_ends_of_their_type() {
+    assert_eq!(Interval::new(7, 2), None);
+    assert_eq!(Interval::new(u8::MIN, u8::MAX).unwrap().length(), 256);
+    assert_eq!(Interval::new(i64::MIN, i64::MAX).unwrap().length(), 1 << 64);
+    assert_eq!(Interval::new(-3i32, 3).unwrap().length(), 7);
+    assert_eq!(Interval::point(5u64).length(), 1);
+
+    let full = Interval::new(0u8, 255).unwrap();
+    assert_eq!(
+        full.difference(&Interval::new(0, 9).unwrap()),
+        [Interval::new(10, 255).unwrap()]
+    );
+    assert_eq!(
+        full.difference(&Interval::new(250, 255).unwrap()),
+        [Interval::new(0, 249).unwrap()]
+    );
+    assert!(!Interval::new(0u8, 9)
+        .unwrap()
+        .touches(&Interval::new(11, 255).unwrap()));
+    assert_eq!(Interval::new(-4i16, 2).unwrap().to_string(), "-4-2");
+}
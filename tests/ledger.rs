@@ -0,0 +1,51 @@
+use std::{env, fs, time::Duration};
+
+use advent_of_code::{
+    ledger::{hash_input, Ledger, Verdict},
+    runner::Answer,
+};
+
+fn answer(part: u8, answer: &str) -> Answer {
+    Answer {
+        day: 1,
+        part,
+        label: "Answer",
+        answer: answer.to_string(),
+        elapsed: Duration::ZERO,
+    }
+}
+
+#[test]
+fn records_and_verifies_answers() {
+    let path = env::temp_dir().join(format!("aoc_ledger_{}.tsv", std::process::id()));
+    let hash = hash_input("1000\n2000\n");
+
+    let mut ledger = Ledger::load(&path).unwrap();
+    assert!(matches!(
+        ledger.check(&answer(1, "3000"), hash),
+        Verdict::Unknown
+    ));
+
+    ledger.record(&answer(1, "3000"), hash);
+    ledger.save().unwrap();
+
+    let ledger = Ledger::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(matches!(
+        ledger.check(&answer(1, "3000"), hash),
+        Verdict::Correct
+    ));
+    assert!(matches!(
+        ledger.check(&answer(2, "3000"), hash),
+        Verdict::Unknown
+    ));
+    assert!(matches!(
+        ledger.check(&answer(1, "2999"), hash),
+        Verdict::Incorrect(expected) if expected == "3000"
+    ));
+    assert!(matches!(
+        ledger.check(&answer(1, "3000"), hash_input("1000\n")),
+        Verdict::Unknown
+    ));
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use advent_of_code::{
+    output::{json_array, json_error, json_record, json_records, json_string},
+    runner::Answer,
+};
+
+#[test]
+fn escapes_json_strings() {
+    assert_eq!(json_string("CMZ"), "\"CMZ\"");
+    assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+}
+
+#[test]
+fn formats_answer_records() {
+    let answer = Answer {
+        day: 5,
+        part: 2,
+        label: "Stack tops 9001",
+        answer: "MCD".to_string(),
+        elapsed: Duration::from_millis(1500),
+    };
+
+    assert_eq!(
+        json_record(&answer),
+        "{\"day\": 5, \"part\": 2, \"answer\": \"MCD\", \"elapsed\": 1.5}"
+    );
+    assert_eq!(json_records(&[]), "[]");
+    assert_eq!(
+        json_records(&[answer]),
+        "[\n  {\"day\": 5, \"part\": 2, \"answer\": \"MCD\", \"elapsed\": 1.5}\n]"
+    );
+}
+
+#[test]
+fn formats_error_records() {
+    assert_eq!(
+        json_error(7, "No input for day 7: \"inputs/day_7.txt\""),
+        "{\"day\": 7, \"error\": \"No input for day 7: \\\"inputs/day_7.txt\\\"\"}"
+    );
+    assert_eq!(
+        json_array(&["{}".to_string(), json_error(8, "Failed")]),
+        "[\n  {},\n  {\"day\": 8, \"error\": \"Failed\"}\n]"
+    );
+}
@@ -0,0 +1,121 @@
+use advent_of_code::{
+    day_1::Day1, day_2::Day2, day_3::Day3, day_4::Day4, day_5::Day5, day_7::Day7, day_8::Day8,
+    parse::ParseError, Solution,
+};
+
+fn parse_error<S: Solution>(input: &str) -> ParseError {
+    match S::parse(input) {
+        Ok(_) => panic!("Expected day {} to reject {:?}", S::DAY, input),
+        Err(error) => error.downcast().unwrap(),
+    }
+}
+
+fn assert_position(error: ParseError, day: u8, line: usize, column: usize, text: &str) {
+    assert_eq!(
+        (error.day, error.line, error.column, error.text.as_str()),
+        (day, line, column, text),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn day_1() {
+    assert_position(parse_error::<Day1>("1000\n\n20x0\n"), 1, 3, 1, "20x0");
+}
+
+#[test]
+fn day_2() {
+    assert_position(parse_error::<Day2>("A Y\nB W\n"), 2, 2, 3, "B W");
+    assert_position(parse_error::<Day2>("A Y\nX Y\n"), 2, 2, 1, "X Y");
+    assert_position(parse_error::<Day2>("A\n"), 2, 1, 1, "A");
+}
+
+#[test]
+fn day_3() {
+    assert_position(parse_error::<Day3>("abAB\nab1B\n"), 3, 2, 3, "ab1B");
+    assert_position(parse_error::<Day3>("abAB\nabcab\n"), 3, 2, 5, "abcab");
+    assert_position(parse_error::<Day3>("aéba\n"), 3, 1, 2, "aéba");
+}
+
+#[test]
+fn day_4() {
+    assert_position(
+        parse_error::<Day4>("2-4,6-8\n2-4,6x8\n"),
+        4,
+        2,
+        5,
+        "2-4,6x8",
+    );
+    assert_position(parse_error::<Day4>("2-4,6-y\n"), 4, 1, 7, "2-4,6-y");
+    assert_position(parse_error::<Day4>("2-4\n"), 4, 1, 1, "2-4");
+    assert_position(parse_error::<Day4>("2-4,8-6\n"), 4, 1, 5, "2-4,8-6");
+}
+
+#[test]
+fn day_5() {
+    let drawing = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+    assert_position(
+        parse_error::<Day5>(&format!("{}move 1 from 3 to 1\n", drawing)),
+        5,
+        5,
+        13,
+        "move 1 from 3 to 1",
+    );
+    assert_position(
+        parse_error::<Day5>(&format!("{}shift 1 from 2 to 1\n", drawing)),
+        5,
+        5,
+        1,
+        "shift 1 from 2 to 1",
+    );
+    assert_position(
+        parse_error::<Day5>("[A] (B)\n 1   2 \n"),
+        5,
+        1,
+        5,
+        "[A] (B)",
+    );
+    assert_position(
+        parse_error::<Day5>("[A]    \n[B] [C]\n"),
+        5,
+        2,
+        1,
+        "[B] [C]",
+    );
+    assert_position(parse_error::<Day5>(""), 5, 1, 1, "");
+}
+
+#[test]
+fn day_7() {
+    assert_position(
+        parse_error::<Day7>("$ cd /\n$ ls\ndir a\n$ cd b\n"),
+        7,
+        4,
+        6,
+        "$ cd b",
+    );
+    assert_position(
+        parse_error::<Day7>("$ cd /\n$ ls\nbig a\n"),
+        7,
+        3,
+        1,
+        "big a",
+    );
+}
+
+#[test]
+fn day_8() {
+    assert_position(parse_error::<Day8>("123\n4a6\n"), 8, 2, 2, "4a6");
+    assert_position(parse_error::<Day8>("123\n45\n"), 8, 2, 1, "45");
+    assert_position(parse_error::<Day8>("  123\n  4a6\n"), 8, 2, 4, "  4a6");
+}
+
+#[test]
+fn displays_the_offending_text() {
+    assert_eq!(
+        parse_error::<Day4>("2-4,6x8\n").to_string(),
+        "Day 4, line 1, column 5: Expected a section range like 2-4\n  |\n1 | 2-4,6x8\n  |     ^"
+    );
+}
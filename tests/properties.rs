@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code::{
+    day_1::{stream_top_elves, Day1},
+    day_2::Day2,
+    day_3::Day3,
+    day_4::Day4,
+    day_5::Day5,
+    day_6::Day6,
+    day_7::Day7,
+    day_8::Day8,
+    generate::generate,
+    Solution,
+};
+
+fn check<S: Solution>(reference: impl Fn(&str) -> (String, String)) {
+    for seed in 0..50 {
+        let size = [1, 2, 3, 10, 100][seed as usize % 5];
+        let input = generate(S::DAY, size, seed).unwrap();
+        let parsed = S::parse(&input).unwrap();
+
+        let answers = (
+            S::part_one(&parsed).unwrap().to_string(),
+            S::part_two(&parsed).unwrap().to_string(),
+        );
+
+        assert_eq!(
+            answers,
+            reference(&input),
+            "Day {} disagrees with the reference on seed {} and size {}:\n{}",
+            S::DAY,
+            seed,
+            size,
+            input
+        );
+    }
+}
+
+#[test]
+fn day_1() {
+    check::<Day1>(|input| {
+        let mut totals: Vec<u32> = input
+            .trim()
+            .split("\n\n")
+            .map(|group| group.lines().map(|line| line.parse::<u32>().unwrap()).sum())
+            .collect();
+        totals.sort_by(|a, b| b.cmp(a));
+
+        (
+            totals[0].to_string(),
+            totals.iter().take(3).sum::<u32>().to_string(),
+        )
+    });
+}
+
+#[test]
+fn day_2() {
+    check::<Day2>(|input| {
+        let mut scores = (0, 0);
+
+        for line in input.lines() {
+            let bytes = line.as_bytes();
+            let opponent = (bytes[0] - b'A') as u32;
+            let column = (bytes[2] - b'X') as u32;
+
+            let outcome = (column + 4 - opponent) % 3;
+            scores.0 += column + 1 + outcome * 3;
+
+            let you = (opponent + column + 2) % 3;
+            scores.1 += you + 1 + column * 3;
+        }
+
+        (scores.0.to_string(), scores.1.to_string())
+    });
+}
+
+fn priority(item: char) -> usize {
+    match item {
+        'a'..='z' => item as usize - 'a' as usize + 1,
+        _ => item as usize - 'A' as usize + 27,
+    }
+}
+
+#[test]
+fn day_3() {
+    check::<Day3>(|input| {
+        let lines: Vec<&str> = input.lines().collect();
+        let items = |line: &str| line.chars().collect::<HashSet<char>>();
+
+        let errors: usize = lines
+            .iter()
+            .map(|line| {
+                let (first, second) = line.split_at(line.len() / 2);
+                items(first)
+                    .intersection(&items(second))
+                    .map(|item| priority(*item))
+                    .sum::<usize>()
+            })
+            .sum();
+
+        let badges: usize = lines
+            .chunks(3)
+            .filter(|group| group.len() == 3)
+            .map(|group| {
+                let common: HashSet<char> =
+                    &(&items(group[0]) & &items(group[1])) & &items(group[2]);
+                common.iter().map(|item| priority(*item)).sum::<usize>()
+            })
+            .sum();
+
+        (errors.to_string(), badges.to_string())
+    });
+}
+
+#[test]
+fn day_4() {
+    check::<Day4>(|input| {
+        let mut counts = (0, 0);
+
+        for line in input.lines() {
+            let sections: Vec<HashSet<u32>> = line
+                .split(',')
+                .map(|range| {
+                    let (min, max) = range.split_once('-').unwrap();
+                    (min.parse().unwrap()..=max.parse().unwrap()).collect()
+                })
+                .collect();
+
+            if sections[0].is_subset(&sections[1]) || sections[1].is_subset(&sections[0]) {
+                counts.0 += 1;
+            }
+
+            if !sections[0].is_disjoint(&sections[1]) {
+                counts.1 += 1;
+            }
+        }
+
+        (counts.0.to_string(), counts.1.to_string())
+    });
+}
+
+#[test]
+fn day_5() {
+    check::<Day5>(|input| {
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        let mut drawing: Vec<&str> = drawing.lines().collect();
+        let count = drawing.pop().unwrap().split_whitespace().count();
+
+        let mut stacks = vec![String::new(); count];
+        for line in drawing.iter().rev() {
+            for (index, stack) in stacks.iter_mut().enumerate() {
+                match line.chars().nth(index * 4 + 1) {
+                    Some(' ') | None => (),
+                    Some(name) => stack.push(name),
+                }
+            }
+        }
+
+        let mut stacks_9001 = stacks.clone();
+
+        for line in moves.lines() {
+            let numbers: Vec<usize> = line
+                .split(' ')
+                .filter_map(|word| word.parse().ok())
+                .collect();
+            let (amount, from, to) = (numbers[0], numbers[1] - 1, numbers[2] - 1);
+
+            for _crate in 0..amount {
+                let name = stacks[from].pop().unwrap();
+                stacks[to].push(name);
+            }
+
+            let start = stacks_9001[from].len() - amount;
+            let moved = stacks_9001[from].split_off(start);
+            stacks_9001[to].push_str(&moved);
+        }
+
+        let tops = |stacks: Vec<String>| -> String {
+            stacks
+                .iter()
+                .filter_map(|stack| stack.chars().last())
+                .collect()
+        };
+
+        (tops(stacks), tops(stacks_9001))
+    });
+}
+
+#[test]
+fn day_6() {
+    check::<Day6>(|input| {
+        let chars: Vec<char> = input.trim().chars().collect();
+        let marker = |size: usize| {
+            (size..=chars.len())
+                .find(|end| chars[end - size..*end].iter().collect::<HashSet<_>>().len() == size)
+                .unwrap()
+        };
+
+        (marker(4).to_string(), marker(14).to_string())
+    });
+}
+
+#[test]
+fn day_7() {
+    check::<Day7>(|input| {
+        let mut path: Vec<&str> = vec![];
+        let mut sizes: HashMap<Vec<&str>, usize> = HashMap::new();
+
+        for line in input.lines() {
+            match line.split(' ').collect::<Vec<&str>>()[..] {
+                ["$", "cd", "/"] => path.clear(),
+                ["$", "cd", ".."] => {
+                    path.pop();
+                }
+                ["$", "cd", name] => path.push(name),
+                ["$", "ls"] | ["dir", _] => (),
+                [size, _name] => {
+                    for depth in 0..=path.len() {
+                        *sizes.entry(path[..depth].to_vec()).or_default() +=
+                            size.parse::<usize>().unwrap();
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        let needed = sizes[&vec![]] - 40_000_000;
+
+        (
+            sizes
+                .values()
+                .filter(|size| **size < 100_000)
+                .sum::<usize>()
+                .to_string(),
+            sizes
+                .values()
+                .filter(|size| **size >= needed)
+                .min()
+                .unwrap()
+                .to_string(),
+        )
+    });
+}
+
+#[test]
+fn day_8() {
+    check::<Day8>(|input| {
+        let grid: Vec<Vec<u32>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|char| char.to_digit(10).unwrap())
+                    .collect()
+            })
+            .collect();
+        let (height, width) = (grid.len(), grid[0].len());
+
+        let directions = |x: usize, y: usize| -> [Vec<u32>; 4] {
+            [
+                (0..x).rev().map(|nx| grid[y][nx]).collect(),
+                (x + 1..width).map(|nx| grid[y][nx]).collect(),
+                (0..y).rev().map(|ny| grid[ny][x]).collect(),
+                (y + 1..height).map(|ny| grid[ny][x]).collect(),
+            ]
+        };
+
+        let mut visible = 0;
+        let mut scenic_score = 0;
+
+        for (y, row) in grid.iter().enumerate() {
+            for (x, tree) in row.iter().copied().enumerate() {
+                let directions = directions(x, y);
+
+                if directions
+                    .iter()
+                    .any(|direction| direction.iter().all(|other| *other < tree))
+                {
+                    visible += 1;
+                }
+
+                let score: usize = directions
+                    .iter()
+                    .map(
+                        |direction| match direction.iter().position(|other| *other >= tree) {
+                            Some(index) => index + 1,
+                            None => direction.len(),
+                        },
+                    )
+                    .product();
+
+                scenic_score = scenic_score.max(score);
+            }
+        }
+
+        (visible.to_string(), scenic_score.to_string())
+    });
+}
+
+#[test]
+fn day_1_streaming() {
+    for seed in 0..20 {
+        let input = generate(1, [1, 2, 3, 10, 100][seed as usize % 5], seed).unwrap();
+        let parsed = Day1::parse(&input).unwrap();
+        let leaders = stream_top_elves(input.as_bytes(), 3).unwrap();
+
+        assert_eq!(leaders[0].total, Day1::part_one(&parsed).unwrap());
+        assert_eq!(
+            leaders.iter().map(|elf| elf.total).sum::<u64>(),
+            Day1::part_two(&parsed).unwrap()
+        );
+    }
+}